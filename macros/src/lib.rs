@@ -1,16 +1,39 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput, ExprPath, Ident, LitStr, Path, Result};
+use syn::{
+    parse::ParseStream, parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, ExprPath,
+    Ident, LitBool, LitStr, Path, Result, Token,
+};
+
+mod register;
 
 fn bevy_ecs_path() -> syn::Path {
     bevy_macro_utils::BevyManifest::default().get_path("bevy_ecs")
 }
 
+fn bevy_app_path() -> syn::Path {
+    bevy_macro_utils::BevyManifest::default().get_path("bevy_app")
+}
+
+fn crate_path() -> syn::Path {
+    bevy_macro_utils::BevyManifest::parse_str("bevy_register_in_world")
+}
+
 fn component_api_path() -> syn::Path {
     bevy_macro_utils::BevyManifest::parse_str("bevy_register_in_world::component")
 }
 
+#[proc_macro_derive(RegisterInWorld, attributes(register))]
+pub fn derive_register_in_world(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match register::derive_register_in_world(ast) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
 
 #[proc_macro_derive(ComponentAutoRegister, attributes(component))]
 pub fn derive_component(input: TokenStream) -> TokenStream {
@@ -18,6 +41,18 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let bevy_ecs_path = bevy_ecs_path();
     let component_api_path = component_api_path(); 
 
+    // Values of such enums can't exist, so the component could never be added
+    if let Data::Enum(data) = &ast.data {
+        if data.variants.is_empty() {
+            return syn::Error::new(
+                ast.ident.span(),
+                "`ComponentAutoRegister` can't be derived for enums without variants",
+            )
+            .into_compile_error()
+            .into();
+        }
+    }
+
     let attrs = match parse_component_attr(&ast) {
         Ok(attrs) => attrs,
         Err(e) => return e.into_compile_error().into(),
@@ -25,7 +60,13 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
-    let on_add = hook_register_on_add_call( attrs.on_add);
+    let on_add = hook_register_on_add_call(
+        attrs.on_add,
+        attrs.register_only,
+        attrs.keep,
+        attrs.require_preregistered,
+        attrs.no_auto_register,
+    );
     let on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
     let on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
     let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
@@ -42,7 +83,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
 
-            #[allow(unused_variables)]
+            #[allow(unused_variables, unused_mut)]
             fn register_component_hooks(hooks: &mut #bevy_ecs_path::component::ComponentHooks) {
                 #on_add
                 #on_insert
@@ -61,6 +102,13 @@ const ON_ADD: &str = "on_add";
 const ON_INSERT: &str = "on_insert";
 const ON_REPLACE: &str = "on_replace";
 const ON_REMOVE: &str = "on_remove";
+const REGISTER_ONLY: &str = "register_only";
+const KEEP: &str = "keep";
+const REQUIRE_PREREGISTERED: &str = "require_preregistered";
+const NO_AUTO_REGISTER: &str = "no_auto_register";
+// relationships are not supported by the targeted bevy version
+const RELATIONSHIP: &str = "relationship";
+const RELATIONSHIP_TARGET: &str = "relationship_target";
 
 struct Attrs {
     storage: StorageTy,
@@ -68,6 +116,10 @@ struct Attrs {
     on_insert: Option<ExprPath>,
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
+    register_only: bool,
+    keep: bool,
+    require_preregistered: bool,
+    no_auto_register: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -87,7 +139,12 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         on_insert: None,
         on_replace: None,
         on_remove: None,
+        register_only: false,
+        keep: false,
+        require_preregistered: false,
+        no_auto_register: false,
     };
+    let mut explicit_storage = None;
 
     for meta in ast.attrs.iter().filter(|a| a.path().is_ident(COMPONENT)) {
         meta.parse_nested_meta(|nested| {
@@ -101,28 +158,88 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                         )));
                     }
                 };
+                explicit_storage = Some(nested.path.span());
+                Ok(())
+            } else if nested.path.is_ident(REGISTER_ONLY) {
+                attrs.register_only = true;
+                Ok(())
+            } else if nested.path.is_ident(REQUIRE_PREREGISTERED) {
+                attrs.require_preregistered = true;
+                Ok(())
+            } else if nested.path.is_ident(NO_AUTO_REGISTER) {
+                attrs.no_auto_register = true;
+                Ok(())
+            } else if nested.path.is_ident(KEEP) {
+                attrs.keep = nested.value()?.parse::<LitBool>()?.value;
                 Ok(())
             } else if nested.path.is_ident(ON_ADD) {
-                attrs.on_add = Some(nested.value()?.parse::<ExprPath>()?);
+                // Bare `on_add` only states that registration is the only thing done on add
+                if nested.input.peek(Token![=]) {
+                    attrs.on_add = Some(parse_hook_path(nested.value()?)?);
+                }
                 Ok(())
             } else if nested.path.is_ident(ON_INSERT) {
-                attrs.on_insert = Some(nested.value()?.parse::<ExprPath>()?);
+                attrs.on_insert = Some(parse_hook_path(nested.value()?)?);
                 Ok(())
             } else if nested.path.is_ident(ON_REPLACE) {
-                attrs.on_replace = Some(nested.value()?.parse::<ExprPath>()?);
+                attrs.on_replace = Some(parse_hook_path(nested.value()?)?);
                 Ok(())
             } else if nested.path.is_ident(ON_REMOVE) {
-                attrs.on_remove = Some(nested.value()?.parse::<ExprPath>()?);
+                attrs.on_remove = Some(parse_hook_path(nested.value()?)?);
                 Ok(())
+            } else if nested.path.is_ident(RELATIONSHIP) || nested.path.is_ident(RELATIONSHIP_TARGET) {
+                Err(nested.error(
+                    "Relationship components are not supported by the targeted bevy version (0.14)",
+                ))
             } else {
                 Err(nested.error("Unsupported attribute"))
             }
         })?;
     }
 
+    if attrs.no_auto_register && (attrs.register_only || attrs.require_preregistered) {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "`{NO_AUTO_REGISTER}` can't be used together with `{REGISTER_ONLY}` or `{REQUIRE_PREREGISTERED}`"
+            ),
+        ));
+    }
+
+    if attrs.register_only && attrs.require_preregistered {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!("`{REGISTER_ONLY}` and `{REQUIRE_PREREGISTERED}` can't be used together"),
+        ));
+    }
+
+    if attrs.register_only {
+        if let (Some(span), StorageTy::Table) = (explicit_storage, attrs.storage) {
+            return Err(syn::Error::new(
+                span,
+                format!("`{REGISTER_ONLY}` components always use '{SPARSE_SET}' storage"),
+            ));
+        }
+        attrs.storage = StorageTy::SparseSet;
+    } else if attrs.keep {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!("`{KEEP}` can only be used together with `{REGISTER_ONLY}`"),
+        ));
+    }
+
     Ok(attrs)
 }
 
+/// Hook paths can be written either as paths, or as string literals containing paths.
+fn parse_hook_path(input: ParseStream) -> Result<ExprPath> {
+    if input.peek(LitStr) {
+        input.parse::<LitStr>()?.parse::<ExprPath>()
+    } else {
+        input.parse::<ExprPath>()
+    }
+}
+
 fn storage_path(bevy_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
     let storage_type = match ty {
         StorageTy::Table => Ident::new("Table", Span::call_site()),
@@ -132,23 +249,75 @@ fn storage_path(bevy_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
     quote! { #bevy_ecs_path::component::StorageType::#storage_type }
 }
 
+/// Wraps the call to the user's hook, so panics name the component and the hook.
+fn hook_call(hook: &str, function: &ExprPath, world: TokenStream2) -> TokenStream2 {
+    let component_api_path = component_api_path();
+    quote! {
+        #component_api_path::call_hook::<Self>(#hook, || (#function)(#world, entity, id));
+    }
+}
+
 fn hook_register_function_call(
     hook: TokenStream2,
     function: Option<ExprPath>,
 ) -> Option<TokenStream2> {
-    function.map(|meta| quote! { hooks. #hook (#meta); })
+    function.map(|meta| {
+        let call = hook_call(&hook.to_string(), &meta, quote! { world });
+        quote! {
+            hooks. #hook (|world, entity, id| {
+                #call
+            });
+        }
+    })
 }
 
 fn hook_register_on_add_call(
     function: Option<ExprPath>,
+    register_only: bool,
+    keep: bool,
+    require_preregistered: bool,
+    no_auto_register: bool,
 ) -> TokenStream2 {
     let component_api_path = component_api_path();
-    let function = function.map(|meta| quote! { (#meta)(world, entity, id); });
+    let function = function.map(|meta| hook_call(ON_ADD, &meta, quote! { world.reborrow() }));
+    // Evaluating the constant fails to compile if the component is not zero-sized
+    let assert_zst = register_only.then(|| {
+        quote! {
+            #[allow(clippy::let_unit_value)]
+            let () = #component_api_path::AssertZeroSized::<Self>::OK;
+        }
+    });
+    // Queued after the user's hook, so it sees the marker
+    let remove = (register_only && !keep).then(|| {
+        quote! {
+            #component_api_path::remove_register_only::<Self>(world, entity);
+        }
+    });
+
+    if no_auto_register && function.is_none() {
+        return TokenStream2::new();
+    }
+
+    // Emitted into the user's crate, so the check follows the user's build profile
+    let register = if no_auto_register {
+        TokenStream2::new()
+    } else if require_preregistered {
+        quote! {
+            #[cfg(debug_assertions)]
+            #component_api_path::assert_preregistered::<Self>(&world);
+        }
+    } else {
+        quote! {
+            #component_api_path::register_on_add::<Self>(world.reborrow(), entity, id);
+        }
+    };
 
     quote! {
+        #assert_zst
         hooks.on_add(|mut world, entity, id| {
-            #component_api_path::register_on_add::<Self>(world.reborrow());
+            #register
             #function
+            #remove
         }); 
     }
 }
@@ -0,0 +1,214 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parenthesized, parse_quote, punctuated::Punctuated, DeriveInput, Expr, ExprPath, GenericParam,
+    Generics, Ident, LitStr, Path, Result, Token, Type,
+};
+
+use crate::{bevy_app_path, bevy_ecs_path, crate_path};
+
+const REGISTER: &str = "register";
+const INIT_RESOURCE: &str = "init_resource";
+const OBSERVERS: &str = "observers";
+const SPAWN: &str = "spawn";
+
+// Schedules from `bevy_app`, that are resolved to their path in `bevy_app`
+const BEVY_APP_SCHEDULES: &[&str] = &[
+    "Main",
+    "PreStartup",
+    "Startup",
+    "PostStartup",
+    "First",
+    "PreUpdate",
+    "RunFixedMainLoop",
+    "FixedFirst",
+    "FixedPreUpdate",
+    "FixedUpdate",
+    "FixedPostUpdate",
+    "FixedLast",
+    "FixedMain",
+    "Update",
+    "SpawnScene",
+    "PostUpdate",
+    "Last",
+];
+
+pub(crate) struct RegisterAttrs {
+    init_resources: Vec<Type>,
+    observers: Vec<ExprPath>,
+    spawns: Vec<Expr>,
+    schedules: Vec<(Path, Vec<ExprPath>)>,
+}
+
+pub(crate) fn parse_register_attr(ast: &DeriveInput) -> Result<RegisterAttrs> {
+    let mut attrs = RegisterAttrs {
+        init_resources: Vec::new(),
+        observers: Vec::new(),
+        spawns: Vec::new(),
+        schedules: Vec::new(),
+    };
+
+    for meta in ast.attrs.iter().filter(|a| a.path().is_ident(REGISTER)) {
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(INIT_RESOURCE) {
+                let value = nested.value()?;
+                let ty = if value.peek(LitStr) {
+                    value.parse::<LitStr>()?.parse::<Type>()?
+                } else {
+                    value.parse::<Type>()?
+                };
+                attrs.init_resources.push(ty);
+                return Ok(());
+            }
+
+            if nested.path.is_ident(OBSERVERS) {
+                let content;
+                parenthesized!(content in nested.input);
+                let observers = Punctuated::<ExprPath, Token![,]>::parse_terminated(&content)?;
+                attrs.observers.extend(observers);
+                return Ok(());
+            }
+
+            if nested.path.is_ident(SPAWN) {
+                let content;
+                parenthesized!(content in nested.input);
+                let bundles = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+                attrs.spawns.extend(bundles);
+                return Ok(());
+            }
+
+            let Some(ident) = nested.path.get_ident() else {
+                return Err(nested.error("Expected schedule name in snake case"));
+            };
+
+            let content;
+            parenthesized!(content in nested.input);
+            let systems = Punctuated::<ExprPath, Token![,]>::parse_terminated(&content)?;
+
+            attrs
+                .schedules
+                .push((schedule_path(ident), systems.into_iter().collect()));
+            Ok(())
+        })?;
+    }
+
+    Ok(attrs)
+}
+
+/// `fixed_update` -> `bevy_app::FixedUpdate`, `my_schedule` -> `MySchedule`
+fn schedule_path(ident: &Ident) -> Path {
+    let camel_case: String = ident
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    let schedule = Ident::new(&camel_case, ident.span());
+
+    if BEVY_APP_SCHEDULES.contains(&camel_case.as_str()) {
+        let bevy_app_path = bevy_app_path();
+        parse_quote! { #bevy_app_path::#schedule }
+    } else {
+        parse_quote! { #schedule }
+    }
+}
+
+/// Bare function names get turbofished with the generic parameters of the type,
+/// everything else is used verbatim.
+pub(crate) fn instantiate(function: &ExprPath, generics: &Generics) -> TokenStream2 {
+    let params: Vec<_> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(ty) => Some(ty.ident.clone()),
+            GenericParam::Const(c) => Some(c.ident.clone()),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+
+    match function.path.get_ident() {
+        Some(ident) if !params.is_empty() => quote! { #ident::<#(#params),*> },
+        _ => quote! { #function },
+    }
+}
+
+/// Adds `Send + Sync + 'static` bounds to every type parameter.
+pub(crate) fn add_bounds(generics: &mut Generics) {
+    let types: Vec<_> = generics.type_params().map(|ty| ty.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for ty in types {
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: ::core::marker::Send + ::core::marker::Sync + 'static });
+    }
+}
+
+pub(crate) fn derive_register_in_world(mut ast: DeriveInput) -> Result<TokenStream2> {
+    let bevy_ecs_path = bevy_ecs_path();
+    let crate_path = crate_path();
+    let attrs = parse_register_attr(&ast)?;
+
+    add_bounds(&mut ast.generics);
+    for ty in &attrs.init_resources {
+        ast.generics.make_where_clause().predicates.push(parse_quote! {
+            #ty: #bevy_ecs_path::system::Resource + #bevy_ecs_path::world::FromWorld
+        });
+    }
+
+    let world = Ident::new("world", Span::mixed_site());
+    let add_systems = attrs
+        .schedules
+        .iter()
+        .filter(|(_, systems)| !systems.is_empty())
+        .map(|(schedule, systems)| {
+            let systems = systems
+                .iter()
+                .map(|system| instantiate(system, &ast.generics));
+            quote! {
+                #crate_path::add_systems::WorldAddSystems::add_systems(
+                    &mut #world,
+                    #schedule,
+                    (#(#systems,)*),
+                );
+            }
+        });
+
+    let init_resources = attrs.init_resources.iter().map(|ty| {
+        quote! {
+            #world.commands().init_resource::<#ty>();
+        }
+    });
+
+    let spawns = attrs.spawns.iter().map(|bundle| {
+        quote! {
+            #world.commands().spawn(#bundle);
+        }
+    });
+
+    let add_observers = attrs.observers.iter().map(|observer| {
+        let observer = instantiate(observer, &ast.generics);
+        quote! {
+            #crate_path::add_observers::WorldAddObserver::add_observer(&mut #world, #observer);
+        }
+    });
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::RegisterInWorld for #struct_name #type_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn register(mut #world: #bevy_ecs_path::world::DeferredWorld) {
+                #(#init_resources)*
+                #(#spawns)*
+                #(#add_systems)*
+                #(#add_observers)*
+            }
+        }
+    })
+}
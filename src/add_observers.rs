@@ -0,0 +1,104 @@
+//! Adding observers
+
+use bevy_consumable_event::ConsumableEvents;
+use bevy_ecs::{
+    bundle::Bundle,
+    event::Event,
+    observer::Observer,
+    system::IntoObserverSystem,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::debug;
+
+/// Spawns the observer during [`AddingSystems`](crate::add_systems::AddingSystems) schedule.
+/// Should use [`ConsumableEventWriter`](bevy_consumable_event::ConsumableEventWriter) to write events.
+#[derive(Event)]
+pub struct AddObserver(Box<dyn FnOnce(&mut World) + Send + Sync>);
+
+impl AddObserver {
+    /// Create instance of the event. Will spawn an entity with the `observer`
+    /// during the run of [`AddingSystems`](crate::add_systems::AddingSystems) schedule.
+    pub fn new<E: Event, B: Bundle, M>(observer: impl IntoObserverSystem<E, B, M>) -> Self {
+        let observer = Observer::new(observer);
+        AddObserver(Box::new(move |world: &mut World| {
+            world.spawn(observer);
+        }))
+    }
+}
+
+/// Consumes all [`AddObserver`] events, and spawns the observers.
+/// This should *only* run during [`AddingSystems`](crate::add_systems::AddingSystems) schedules.
+/// If you're not using [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin),
+/// add this system to the [`AddingSystems`](crate::add_systems::AddingSystems) schedule.
+pub fn add_requested_observers(world: &mut World) {
+    let Some(mut events) = world.get_resource_mut::<ConsumableEvents<AddObserver>>() else {
+        return;
+    };
+    let observers: Vec<_> = events.read().map(|event| event.consume()).collect();
+
+    if !observers.is_empty() {
+        debug!("Adding {} observers", observers.len());
+    }
+    for AddObserver(spawn) in observers {
+        spawn(world);
+    }
+}
+
+/// Convenience trait to add observers to the world.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+///
+/// #[derive(Event)]
+/// struct Ping;
+///
+/// #[derive(Resource, Default)]
+/// struct Pinged(bool);
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_observer(|_trigger: Trigger<Ping>, mut pinged: ResMut<Pinged>| pinged.0 = true);
+///     }
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.world_mut().init_resource::<Pinged>();
+///
+/// harness.register::<Foo>().flush_registrations();
+///
+/// harness.world_mut().trigger(Ping);
+/// assert!(harness.world().resource::<Pinged>().0);
+/// ```
+pub trait WorldAddObserver {
+    /// Sends [`AddObserver`] event.
+    fn add_observer<E: Event, B: Bundle, M>(&mut self, observer: impl IntoObserverSystem<E, B, M>);
+}
+
+impl WorldAddObserver for DeferredWorld<'_> {
+    /// If [`ConsumableEvents<AddObserver>`] doesn't exist yet, the event is sent through commands,
+    /// which initialize the resource first.
+    fn add_observer<E: Event, B: Bundle, M>(&mut self, observer: impl IntoObserverSystem<E, B, M>) {
+        let event = AddObserver::new(observer);
+
+        match self.get_resource_mut::<ConsumableEvents<AddObserver>>() {
+            Some(mut events) => events.send(event),
+            None => self.commands().add(|world: &mut World| {
+                world
+                    .get_resource_or_insert_with::<ConsumableEvents<AddObserver>>(Default::default)
+                    .send(event);
+            }),
+        }
+    }
+}
+
+impl WorldAddObserver for World {
+    /// Initializes [`ConsumableEvents<AddObserver>`] if it doesn't exist yet.
+    fn add_observer<E: Event, B: Bundle, M>(&mut self, observer: impl IntoObserverSystem<E, B, M>) {
+        self.get_resource_or_insert_with::<ConsumableEvents<AddObserver>>(Default::default)
+            .send(AddObserver::new(observer));
+    }
+}
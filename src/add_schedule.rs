@@ -0,0 +1,221 @@
+//! Adding schedules
+
+use bevy_consumable_event::{Consume, ConsumableEvents};
+use bevy_ecs::{
+    event::Event,
+    schedule::{InternedScheduleLabel, Schedule, ScheduleLabel, Schedules},
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::{debug, warn};
+#[cfg(feature = "bevy_app")]
+use {
+    crate::app::AddingSystemsPlacement,
+    bevy_app::MainScheduleOrder,
+    bevy_ecs::system::Resource,
+};
+
+/// Inserts the schedule into [`Schedules`] during [`AddingSystems`](crate::add_systems::AddingSystems) schedule,
+/// before the requested systems are added, so [`AddSystems`](crate::add_systems::AddSystems) events sent together
+/// with it add systems to it. If a schedule with the same label already exists, it's kept.
+/// Should use [`ConsumableEventWriter`](bevy_consumable_event::ConsumableEventWriter) to write events.
+///
+/// The schedule can also be placed in the [`MainScheduleOrder`],
+/// so it runs every frame. Since the order is taken out of the world while [`Main`](bevy_app::Main) runs,
+/// it's updated by [`apply_pending_main_schedule_order`] right after, and the schedule runs starting the next frame.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::prelude::*;
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct AiUpdate;
+///
+/// #[derive(Resource, Default)]
+/// struct Thoughts(u32);
+///
+/// struct Ai;
+///
+/// impl RegisterInWorld for Ai {
+///     fn register(mut world: DeferredWorld) {
+///         world.request_schedule(AddSchedule::new(AiUpdate).after(Update));
+///         world.add_systems(AiUpdate, |mut thoughts: ResMut<Thoughts>| thoughts.0 += 1);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.init_resource::<Thoughts>();
+///
+/// app.register::<Ai>();
+/// app.update();
+/// assert!(app.world().resource::<Schedules>().contains(AiUpdate));
+/// assert_eq!(app.world().resource::<Thoughts>().0, 0);
+///
+/// app.update();
+/// app.update();
+/// assert_eq!(app.world().resource::<Thoughts>().0, 2);
+/// ```
+#[derive(Event)]
+pub struct AddSchedule {
+    schedule: Schedule,
+    #[cfg(feature = "bevy_app")]
+    placement: Option<AddingSystemsPlacement>,
+}
+
+impl AddSchedule {
+    /// Creates the event adding an empty schedule with the `label`.
+    pub fn new(label: impl ScheduleLabel) -> Self {
+        Schedule::new(label).into()
+    }
+
+    /// Label of the added schedule.
+    #[inline]
+    pub fn label(&self) -> InternedScheduleLabel {
+        self.schedule.label()
+    }
+
+    /// Places the schedule in the [`MainScheduleOrder`] right after the `anchor`.
+    /// If the `anchor` is not in the order, a warning is logged and the schedule is not placed.
+    #[cfg(feature = "bevy_app")]
+    pub fn after(mut self, anchor: impl ScheduleLabel) -> Self {
+        self.placement = Some(AddingSystemsPlacement::After(anchor.intern()));
+        self
+    }
+
+    /// Places the schedule in the [`MainScheduleOrder`] right before the `anchor`.
+    /// If the `anchor` is not in the order, a warning is logged and the schedule is not placed.
+    #[cfg(feature = "bevy_app")]
+    pub fn before(mut self, anchor: impl ScheduleLabel) -> Self {
+        self.placement = Some(AddingSystemsPlacement::Before(anchor.intern()));
+        self
+    }
+}
+
+impl From<Schedule> for AddSchedule {
+    /// The schedule is inserted as is, keeping its systems and settings.
+    fn from(schedule: Schedule) -> Self {
+        AddSchedule {
+            schedule,
+            #[cfg(feature = "bevy_app")]
+            placement: None,
+        }
+    }
+}
+
+/// Consumes all [`AddSchedule`] events, and inserts the schedules that don't exist yet.
+/// This should *only* run during [`AddingSystems`](crate::add_systems::AddingSystems) schedules,
+/// in [`RegisterInWorldSet::ApplyAddSchedules`](crate::add_systems::RegisterInWorldSet::ApplyAddSchedules).
+/// If you're not using [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin),
+/// add this system to the [`AddingSystems`](crate::add_systems::AddingSystems) schedule,
+/// before [`add_requested_systems`](crate::add_systems::add_requested_systems).
+///
+/// If [`Schedules`] doesn't exist, a warning is logged and the events are left unconsumed.
+pub fn add_requested_schedules(world: &mut World) {
+    let has_schedules = world.contains_resource::<Schedules>();
+    let Some(mut events) = world.get_resource_mut::<ConsumableEvents<AddSchedule>>() else {
+        return;
+    };
+    if !has_schedules {
+        if events.read().next().is_some() {
+            warn!("`Schedules` doesn't exist, adding the requested schedules is postponed until it's back");
+        }
+        return;
+    }
+    let requested: Vec<_> = events.read().map(Consume::consume).collect();
+
+    for event in requested {
+        let label = event.label();
+        let mut schedules = world.resource_mut::<Schedules>();
+        if schedules.contains(label) {
+            debug!("{label:?} already exists, keeping it");
+        } else {
+            debug!("Adding {label:?}");
+            schedules.insert(event.schedule);
+        }
+
+        #[cfg(feature = "bevy_app")]
+        if let Some(placement) = event.placement {
+            match world.get_resource_mut::<MainScheduleOrder>() {
+                Some(mut order) => place_in_order(&mut order, label, placement),
+                // Taken out of the world while `Main` runs
+                None => world
+                    .get_resource_or_insert_with::<PendingMainScheduleOrder>(Default::default)
+                    .0
+                    .push((label, placement)),
+            }
+        }
+    }
+}
+
+/// Placements of schedules in the [`MainScheduleOrder`] that are applied after [`Main`](bevy_app::Main) runs.
+#[cfg(feature = "bevy_app")]
+#[derive(Resource, Default)]
+struct PendingMainScheduleOrder(Vec<(InternedScheduleLabel, AddingSystemsPlacement)>);
+
+/// Places the schedules requested by [`AddSchedule`] events in the [`MainScheduleOrder`],
+/// if they were requested while [`Main`](bevy_app::Main) was running.
+/// Added to [`Main`](bevy_app::Main) after [`Main::run_main`](bevy_app::Main::run_main)
+/// by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+#[cfg(feature = "bevy_app")]
+pub fn apply_pending_main_schedule_order(world: &mut World) {
+    let Some(mut pending) = world.get_resource_mut::<PendingMainScheduleOrder>() else {
+        return;
+    };
+    let pending = std::mem::take(&mut pending.0);
+    if pending.is_empty() {
+        return;
+    }
+    let Some(mut order) = world.get_resource_mut::<MainScheduleOrder>() else {
+        warn!("`MainScheduleOrder` doesn't exist, requested schedules are not placed in it");
+        return;
+    };
+    for (label, placement) in pending {
+        place_in_order(&mut order, label, placement);
+    }
+}
+
+/// Inserts the `label` into the `order`, unless it's already there.
+#[cfg(feature = "bevy_app")]
+fn place_in_order(order: &mut MainScheduleOrder, label: InternedScheduleLabel, placement: AddingSystemsPlacement) {
+    if order.labels.contains(&label) {
+        return;
+    }
+    let anchor = placement.anchor();
+    let Some(index) = order.labels.iter().position(|&current| current == anchor) else {
+        warn!("Can't place {label:?} relative to {anchor:?}: the schedule is not in the `MainScheduleOrder`");
+        return;
+    };
+    let index = match placement {
+        AddingSystemsPlacement::After(_) => index + 1,
+        AddingSystemsPlacement::Before(_) => index,
+    };
+    order.labels.insert(index, label);
+}
+
+/// Convenience trait to send [`AddSchedule`] events.
+pub trait WorldAddSchedule {
+    /// Sends the [`AddSchedule`] event.
+    fn request_schedule(&mut self, schedule: AddSchedule);
+}
+
+impl WorldAddSchedule for DeferredWorld<'_> {
+    /// If [`ConsumableEvents<AddSchedule>`] doesn't exist yet, the event is sent through commands,
+    /// which initialize the resource first.
+    fn request_schedule(&mut self, schedule: AddSchedule) {
+        match self.get_resource_mut::<ConsumableEvents<AddSchedule>>() {
+            Some(mut events) => events.send(schedule),
+            None => self.commands().add(|world: &mut World| world.request_schedule(schedule)),
+        }
+    }
+}
+
+impl WorldAddSchedule for World {
+    /// Initializes [`ConsumableEvents<AddSchedule>`] if it doesn't exist yet.
+    fn request_schedule(&mut self, schedule: AddSchedule) {
+        self.get_resource_or_insert_with::<ConsumableEvents<AddSchedule>>(Default::default)
+            .send(schedule);
+    }
+}
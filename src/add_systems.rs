@@ -1,50 +1,1570 @@
 //! Adding systems
 
-use bevy_consumable_event::{ConsumableEventReader, ConsumableEvents};
+use bevy_consumable_event::{Consume, ConsumableEventReader, ConsumableEvents};
 use bevy_ecs::{
     event::Event,
-    schedule::{InternedScheduleLabel, IntoSystemConfigs, ScheduleLabel, Schedules, SystemConfigs},
-    system::ResMut,
+    schedule::{
+        apply_deferred, Chain, InternedScheduleLabel, IntoSystemConfigs, IntoSystemSetConfigs, NodeConfigs, NodeId,
+        ScheduleLabel, Schedules, SystemConfigs, SystemSet,
+    },
+    change_detection::Mut,
+    system::{Res, ResMut, Resource, SystemState},
     world::{DeferredWorld, World},
 };
+use bevy_utils::{
+    tracing::{debug, error, warn},
+    HashMap,
+};
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    cmp::Reverse,
+    hash::Hash,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+use thiserror::Error;
+#[cfg(feature = "debug-add-systems")]
+use bevy_ecs::{change_detection::DetectChanges, system::Local};
 
-/// Schedule that is executed after [`Last`](bevy_app::Last) schedule. 
-/// During this schedule *only one system* should be called - [`add_requested_systems`].
+use crate::{
+    configure_sets::{apply_set_configs, WorldConfigureSets},
+    KeySet, RegisteredTypes,
+};
+#[cfg(feature = "trace")]
+use bevy_utils::tracing::info_span;
+
+/// Schedule that is executed after [`Last`](bevy_app::Last) schedule, 
+/// unless configured otherwise in [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+/// During this schedule only [`add_requested_systems`] 
+/// and [`add_requested_observers`](crate::add_observers::add_requested_observers) should be called.
 /// It's not recommended to add any other systems to it.
 /// This schedule is only used for adding systems to other schedules, so adding systems to it
 /// using [`AddSystems`] event is impossible.
 #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
 pub struct AddingSystems;
 
+/// Schedule label created from a string at runtime, for example from a mod description.
+/// Use [`DynScheduleLabel::resolve`] or [`WorldAddSystems::add_systems_by_name`] to get
+/// the built-in schedule when the name is well known.
+#[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+pub struct DynScheduleLabel(pub Cow<'static, str>);
+
+impl DynScheduleLabel {
+    /// Creates the label with the `name`.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        DynScheduleLabel(name.into())
+    }
+
+    /// Returns the `bevy_app` schedule with the `name` in snake case (`"update"` is [`Update`](bevy_app::Update),
+    /// `"fixed_update"` is [`FixedUpdate`](bevy_app::FixedUpdate), ...),
+    /// or [`DynScheduleLabel`] with the `name` if it's not a name of a `bevy_app` schedule.
+    pub fn resolve(name: impl Into<Cow<'static, str>>) -> InternedScheduleLabel {
+        let name = name.into();
+        #[cfg(feature = "bevy_app")]
+        if let Some(label) = bevy_app_schedule(&name) {
+            return label;
+        }
+        DynScheduleLabel(name).intern()
+    }
+}
+
+/// Schedules of `bevy_app` by their names in snake case.
+#[cfg(feature = "bevy_app")]
+fn bevy_app_schedule(name: &str) -> Option<InternedScheduleLabel> {
+    use bevy_app::*;
+
+    Some(match name {
+        "main" => Main.intern(),
+        "pre_startup" => PreStartup.intern(),
+        "startup" => Startup.intern(),
+        "post_startup" => PostStartup.intern(),
+        "first" => First.intern(),
+        "pre_update" => PreUpdate.intern(),
+        "run_fixed_main_loop" => RunFixedMainLoop.intern(),
+        "fixed_first" => FixedFirst.intern(),
+        "fixed_pre_update" => FixedPreUpdate.intern(),
+        "fixed_update" => FixedUpdate.intern(),
+        "fixed_post_update" => FixedPostUpdate.intern(),
+        "fixed_last" => FixedLast.intern(),
+        "fixed_main" => FixedMain.intern(),
+        "update" => Update.intern(),
+        "spawn_scene" => SpawnScene.intern(),
+        "post_update" => PostUpdate.intern(),
+        "last" => Last.intern(),
+        _ => return None,
+    })
+}
+
+/// Label of the schedule that is used instead of [`AddingSystems`], if the resource exists.
+/// Systems can't be added to it using [`AddSystems`] events, the same as with [`AddingSystems`].
+/// Inserted by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddingSystemsLabel(pub InternedScheduleLabel);
+
+impl Default for AddingSystemsLabel {
+    fn default() -> Self {
+        AddingSystemsLabel(AddingSystems.intern())
+    }
+}
+
+/// Returns `true` if systems can't be added to the `schedule`, because it's [`AddingSystems`]
+/// or the schedule in [`AddingSystemsLabel`].
+pub(crate) fn is_adding_systems(world: &World, schedule: InternedScheduleLabel) -> bool {
+    schedule.as_dyn_eq().dyn_eq(&AddingSystems)
+        || world
+            .get_resource::<AddingSystemsLabel>()
+            .is_some_and(|label| label.0 == schedule)
+}
+
+/// Checks the `schedule` against [`AddingSystemsLabel`] and the schedules forbidden by [`AddSystemsPolicy`].
+fn check_schedule(world: &World, schedule: InternedScheduleLabel) -> Result<(), AddSystemsError> {
+    if is_adding_systems(world, schedule) {
+        return Err(AddSystemsError::AddingSystemsSchedule);
+    }
+    if world
+        .get_resource::<AddSystemsPolicy>()
+        .is_some_and(|policy| policy.forbidden.contains(&schedule))
+    {
+        return Err(AddSystemsError::ForbiddenSchedule(schedule));
+    }
+    Ok(())
+}
+
+/// Panics with the `err`, or logs it if [`AddSystemsPolicy`] rejects forbidden schedules.
+fn reject(world: &World, err: AddSystemsError) {
+    let policy = world
+        .get_resource::<AddSystemsPolicy>()
+        .map_or(ForbiddenSchedulePolicy::Panic, |policy| policy.on_forbidden_schedule);
+    match policy {
+        ForbiddenSchedulePolicy::Panic => panic!("{err}"),
+        ForbiddenSchedulePolicy::Reject => error!("{err} The systems are dropped."),
+    }
+}
+
+/// How [`WorldAddSystems`] treats requests to add systems to a forbidden schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ForbiddenSchedulePolicy {
+    /// Logs an error and drops the systems.
+    Reject,
+    /// Panics, the same as [`AddSystems::new`].
+    #[default]
+    Panic,
+}
+
+/// Schedules [`WorldAddSystems`] doesn't add systems to, on top of [`AddingSystems`] and [`AddingSystemsLabel`],
+/// and what happens when systems are requested for them. Without the resource, such requests panic.
+/// Use it when schedule labels come from untrusted sources, for example mod manifests.
+///
+/// `try_*` methods of [`WorldAddSystems`] return [`AddSystemsError::ForbiddenSchedule`] for the forbidden
+/// schedules regardless of the policy. Events sent directly to [`ConsumableEvents<AddSystems>`] are not checked.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// use bevy_register_in_world::{
+///     add_systems::{
+///         AddSystemsError, AddSystemsPolicy, AddingSystems, DynScheduleLabel, ForbiddenSchedulePolicy, SystemAddition,
+///     },
+///     prelude::*,
+/// };
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct NetworkSend;
+///
+/// #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct SendSet;
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.insert_resource(AddSystemsPolicy {
+///     on_forbidden_schedule: ForbiddenSchedulePolicy::Reject,
+///     forbidden: vec![NetworkSend.intern()],
+/// });
+///
+/// let world = app.world_mut();
+/// world.add_systems(NetworkSend, || {});
+/// world.add_systems(AddingSystems, || {});
+/// world.add_systems_with_barrier(NetworkSend, || {});
+/// world.add_systems_in_set(AddingSystems, SendSet, || {}, SendSet);
+/// world.add(SystemAddition::new(NetworkSend, || {}).and(AddingSystems, || {}));
+/// world.add_systems_by_name("update", || {});
+/// assert_eq!(
+///     world.try_add_systems(NetworkSend, || {}),
+///     Err(AddSystemsError::ForbiddenSchedule(NetworkSend.intern())),
+/// );
+///
+/// app.update();
+/// let schedules = app.world().resource::<Schedules>();
+/// assert!(schedules.get(NetworkSend).is_none());
+/// assert_eq!(schedules.get(Update).unwrap().systems_len(), 1);
+/// ```
+///
+/// ```should_panic
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// use bevy_register_in_world::{add_systems::AddSystemsPolicy, prelude::*};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct NetworkSend;
+///
+/// let mut world = World::new();
+/// world.insert_resource(AddSystemsPolicy {
+///     forbidden: vec![NetworkSend.intern()],
+///     ..Default::default()
+/// });
+/// world.add_systems(NetworkSend, || {});
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AddSystemsPolicy {
+    /// What happens when systems are requested for a forbidden schedule.
+    pub on_forbidden_schedule: ForbiddenSchedulePolicy,
+    /// Schedules that are forbidden in addition to [`AddingSystems`] and [`AddingSystemsLabel`].
+    pub forbidden: Vec<InternedScheduleLabel>,
+}
+
+/// Sends the `event`, or sends it through commands if [`ConsumableEvents<AddSystems>`] doesn't exist.
+fn send_deferred(world: &mut DeferredWorld, event: AddSystems) {
+    let event = event.stamped(world);
+    if !world.contains_resource::<ConsumableEvents<AddSystems>>() {
+        world.commands().add(move |world: &mut World| send_world(world, event));
+        return;
+    }
+    let event = event.counted(world.get_resource_mut());
+    world.resource_mut::<ConsumableEvents<AddSystems>>().send(event);
+}
+
+/// Sends the `event`, initializing [`ConsumableEvents<AddSystems>`] if it doesn't exist.
+fn send_world(world: &mut World, event: AddSystems) {
+    let event = event.stamped(world).counted(world.get_resource_mut());
+    world
+        .get_resource_or_insert_with::<ConsumableEvents<AddSystems>>(Default::default)
+        .send(event);
+}
+
+/// System sets of the systems this crate adds to [`AddingSystems`] schedule.
+/// Use them to order your own systems in [`AddingSystems`] or to find the systems in the schedule graph.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{
+///     add_systems::{AddingSystems, RegisterInWorldSet},
+///     test_utils::RegistrationTestHarness,
+/// };
+///
+/// let harness = RegistrationTestHarness::new();
+///
+/// let schedules = harness.world().resource::<Schedules>();
+/// let graph = schedules.get(AddingSystems).unwrap().graph();
+/// let (set, _, _) = graph
+///     .system_sets()
+///     .find(|(_, set, _)| set.as_dyn_eq().dyn_eq(&RegisterInWorldSet::ApplyAddSystems))
+///     .unwrap();
+/// let (system, _, _) = graph
+///     .systems()
+///     .find(|(_, system, _)| system.name() == "bevy_register_in_world::add_systems::add_requested_systems")
+///     .unwrap();
+/// assert!(graph.hierarchy().graph().contains_edge(set, system));
+/// ```
+#[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegisterInWorldSet {
+    /// Contains [`apply_register_types`](crate::register_types::apply_register_types),
+    /// runs before [`ApplyDeferredRegistrations`](RegisterInWorldSet::ApplyDeferredRegistrations).
+    ApplyRegisterTypes,
+    /// Contains [`apply_deferred_registrations`](crate::deferred::apply_deferred_registrations),
+    /// runs before [`ApplyAddSchedules`](RegisterInWorldSet::ApplyAddSchedules).
+    ApplyDeferredRegistrations,
+    /// Contains [`add_requested_schedules`](crate::add_schedule::add_requested_schedules),
+    /// runs before [`ApplyConfigureSets`](RegisterInWorldSet::ApplyConfigureSets).
+    ApplyAddSchedules,
+    /// Contains [`apply_requested_set_configs`](crate::configure_sets::apply_requested_set_configs),
+    /// runs before [`ApplyAddSystems`](RegisterInWorldSet::ApplyAddSystems).
+    ApplyConfigureSets,
+    /// Contains [`add_requested_systems`].
+    ApplyAddSystems,
+    /// Contains [`add_requested_observers`](crate::add_observers::add_requested_observers).
+    ApplyAddObservers,
+}
+
+/// Systems added to a schedule by [`AddSystems`] events, between the [barriers](AddSystems::with_barrier).
+/// The set with the number `n + 1` runs after the set with the number `n`, with [`apply_deferred`] between them.
+#[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct RuntimeAddedSet(pub u32);
+
+/// Systems that should run during [`AddingSystems`], in their [`RegisterInWorldSet`]s,
+/// followed by [`clear_consumed_requests`].
+#[cfg(any(feature = "bevy_app", feature = "test-utils"))]
+pub(crate) fn adding_systems_configs() -> SystemConfigs {
+    (
+        crate::register_types::apply_register_types
+            .in_set(RegisterInWorldSet::ApplyRegisterTypes)
+            .before(RegisterInWorldSet::ApplyDeferredRegistrations),
+        crate::deferred::apply_deferred_registrations
+            .in_set(RegisterInWorldSet::ApplyDeferredRegistrations)
+            .before(RegisterInWorldSet::ApplyAddSchedules),
+        crate::add_schedule::add_requested_schedules
+            .in_set(RegisterInWorldSet::ApplyAddSchedules)
+            .before(RegisterInWorldSet::ApplyConfigureSets),
+        crate::configure_sets::apply_requested_set_configs
+            .in_set(RegisterInWorldSet::ApplyConfigureSets)
+            .before(RegisterInWorldSet::ApplyAddSystems),
+        add_requested_systems.in_set(RegisterInWorldSet::ApplyAddSystems),
+        crate::add_observers::add_requested_observers.in_set(RegisterInWorldSet::ApplyAddObservers),
+        clear_consumed_requests
+            .after(RegisterInWorldSet::ApplyAddSystems)
+            .after(RegisterInWorldSet::ApplyAddObservers),
+    )
+        .into_configs()
+}
+
+/// Initializes the resources that the systems in [`AddingSystems`] read and update:
+/// [`DeferredRegistrations`](crate::deferred::DeferredRegistrations), the event buffers,
+/// [`SystemAdditionStats`], [`RuntimeAddedSystems`], [`SystemsAddedOnce`], [`PendingSystemAdditions`]
+/// and [`AdditionsGeneration`].
+#[cfg(any(feature = "bevy_app", feature = "test-utils"))]
+pub(crate) fn init_adding_systems_resources(world: &mut World) {
+    world.init_resource::<crate::deferred::DeferredRegistrations>();
+    world.init_resource::<ConsumableEvents<AddSystems>>();
+    world.init_resource::<ConsumableEvents<crate::add_observers::AddObserver>>();
+    world.init_resource::<ConsumableEvents<crate::register_types::RegisterTypes>>();
+    world.init_resource::<ConsumableEvents<crate::add_schedule::AddSchedule>>();
+    world.init_resource::<ConsumableEvents<crate::configure_sets::ConfigureSets>>();
+    world.init_resource::<SystemAdditionStats>();
+    world.init_resource::<RuntimeAddedSystems>();
+    world.init_resource::<SystemsAddedOnce>();
+    world.init_resource::<PendingSystemAdditions>();
+    world.init_resource::<AdditionsGeneration>();
+}
+
+/// Removes the consumed events from the buffers initialized by [`init_adding_systems_resources`].
+/// Runs at the end of [`AddingSystems`], the only place the events are cleared in,
+/// so the events consumed between fixed steps are cleared there too.
+#[cfg(any(feature = "bevy_app", feature = "test-utils"))]
+fn clear_consumed_requests(world: &mut World) {
+    fn clear<E: Event>(world: &mut World) {
+        if let Some(mut events) = world.get_resource_mut::<ConsumableEvents<E>>() {
+            events.clear_consumed();
+        }
+    }
+
+    clear::<AddSystems>(world);
+    clear::<crate::add_observers::AddObserver>(world);
+    clear::<crate::register_types::RegisterTypes>(world);
+    clear::<crate::add_schedule::AddSchedule>(world);
+    clear::<crate::configure_sets::ConfigureSets>(world);
+}
+
 /// Adds systems to the schedule during [`AddingSystems`] schedule.
 /// Should use [`ConsumableEventWriter`](bevy_consumable_event::ConsumableEventWriter) to write events.
+///
+/// Events with higher [priority](AddSystems::with_priority) are applied first,
+/// events with the same priority are applied in the order they were sent in.
 #[derive(Event)]
-pub struct AddSystems(InternedScheduleLabel, SystemConfigs);
+pub struct AddSystems {
+    label: InternedScheduleLabel,
+    configs: SystemConfigs,
+    priority: i32,
+    barrier: bool,
+    generation: Option<u32>,
+    origin: Option<(TypeId, &'static str)>,
+    counted: bool,
+}
 
 impl AddSystems {
     /// Create instance of the event. Will add `systems` in `schedule` during the run of [`AddingSystems`] schedule
     /// # Panics
     /// If trying to use [`AddingSystems`] as label to add systems to. 
+    /// The schedule in [`AddingSystemsLabel`] is not checked here, see [`try_new`](AddSystems::try_new).
     pub fn new<M>(schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) -> Self {
-        let schedule = schedule.intern();
-        assert!(!schedule.as_dyn_eq().dyn_eq(&AddingSystems), "Trying to add systems to `AddingSystems` schedule using `AddSystems` event. This is not allowed since `AddSystems` events are consumed during `AddingSystems` schedule.");
-        AddSystems(schedule, systems.into_configs())
+        match Self::try_new(schedule, systems) {
+            Ok(event) => event,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Create instance of the event. Will add `systems` in `schedule` during the run of [`AddingSystems`] schedule.
+    /// Returns [`AddSystemsError::AddingSystemsSchedule`] if trying to use [`AddingSystems`] as label to add systems to.
+    ///
+    /// The schedule in [`AddingSystemsLabel`] is a resource of the world, so it's not checked here.
+    /// [`WorldAddSystems`] rejects it when the event is sent, and events for it sent directly
+    /// to [`ConsumableEvents<AddSystems>`] are skipped with an error when applied.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// use bevy_consumable_event::ConsumableEvents;
+    /// use bevy_register_in_world::{add_systems::AddSystemsError, prelude::*};
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct CustomAdding;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Ran(bool);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default().adding_systems_schedule(CustomAdding));
+    /// app.init_resource::<Ran>();
+    ///
+    /// let event = AddSystems::try_new(CustomAdding, |mut ran: ResMut<Ran>| ran.0 = true).unwrap();
+    /// assert_eq!(
+    ///     app.world_mut().try_add_systems(CustomAdding, || {}),
+    ///     Err(AddSystemsError::AddingSystemsSchedule),
+    /// );
+    ///
+    /// app.world_mut().resource_mut::<ConsumableEvents<AddSystems>>().send(event);
+    /// app.update();
+    /// app.update();
+    /// assert!(!app.world().resource::<Ran>().0);
+    /// ```
+    pub fn try_new<M>(
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> Result<Self, AddSystemsError> {
+        let event = Self::unchecked(schedule, systems);
+        if event.label.as_dyn_eq().dyn_eq(&AddingSystems) {
+            return Err(AddSystemsError::AddingSystemsSchedule);
+        }
+        Ok(event)
+    }
+
+    /// Creates the event without checking the schedule, for the events that are checked when sent.
+    fn unchecked<M>(schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) -> Self {
+        AddSystems {
+            label: schedule.intern(),
+            configs: systems.into_configs(),
+            priority: 0,
+            barrier: false,
+            generation: None,
+            origin: None,
+            counted: false,
+        }
+    }
+
+    /// Same as [`try_new`](AddSystems::try_new), but also checks the schedule against
+    /// the `world`'s [`AddingSystemsLabel`] and [`AddSystemsPolicy`].
+    fn try_new_in<M>(
+        world: &World,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> Result<Self, AddSystemsError> {
+        let event = Self::try_new(schedule, systems)?;
+        check_schedule(world, event.label)?;
+        Ok(event)
+    }
+
+    /// The schedule systems will be added to.
+    #[inline]
+    pub fn schedule(&self) -> InternedScheduleLabel {
+        self.label
+    }
+
+    /// Sets the priority of the event. Events with higher priority are applied before
+    /// the events with lower priority, that were sent during the same frame. Default priority is `0`.
+    ///
+    /// Use it to add the systems that establish something other systems rely on, for example
+    /// system sets that other systems are ordered relative to.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// use bevy_consumable_event::ConsumableEvents;
+    /// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct MySchedule;
+    ///
+    /// #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct Setup;
+    ///
+    /// fn setup() {}
+    /// fn after_setup() {}
+    ///
+    /// let mut harness = RegistrationTestHarness::new();
+    /// let mut events = harness.world_mut().resource_mut::<ConsumableEvents<AddSystems>>();
+    /// events.send(AddSystems::new(MySchedule, after_setup.after(Setup)));
+    /// events.send(AddSystems::new(MySchedule, setup.in_set(Setup)).with_priority(10));
+    ///
+    /// harness.flush_registrations();
+    ///
+    /// let schedules = harness.world().resource::<Schedules>();
+    /// let names: Vec<_> = schedules.get(MySchedule).unwrap()
+    ///     .graph()
+    ///     .systems()
+    ///     .map(|(_, system, _)| system.name())
+    ///     .collect();
+    /// assert!(names[0].ends_with("::setup"));
+    /// assert!(names[1].ends_with("::after_setup"));
+    /// ```
+    #[inline]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Priority of the event, see [`with_priority`](AddSystems::with_priority).
+    #[inline]
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Puts a sync point before the systems: they run after all the systems that were added
+    /// to the same schedule by earlier events, with [`apply_deferred`] between them, so commands
+    /// of the earlier systems are applied before these systems run, in the same frame.
+    ///
+    /// Every event is applied in a [`RuntimeAddedSet`] of its schedule, and an event with a barrier
+    /// starts the next one. Barriers are ordered after the earlier barriers only if [`RuntimeAddedSystems`] exists.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_register_in_world::{add_systems::AddSystems, prelude::*};
+    ///
+    /// #[derive(Resource)]
+    /// struct Config;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Seen(Vec<bool>);
+    ///
+    /// fn setup(mut commands: Commands) {
+    ///     commands.insert_resource(Config);
+    /// }
+    ///
+    /// fn consumer(config: Option<Res<Config>>, mut seen: ResMut<Seen>) {
+    ///     seen.0.push(config.is_some());
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<Seen>();
+    ///
+    /// let world = app.world_mut();
+    /// world.add_systems(Update, setup);
+    /// world.add_systems_with_barrier(Update, consumer);
+    /// # assert!(AddSystems::new(Update, consumer).with_barrier().has_barrier());
+    /// app.update();
+    ///
+    /// // Both systems run for the first time in the same frame
+    /// app.update();
+    /// assert_eq!(app.world().resource::<Seen>().0, [true]);
+    /// ```
+    #[inline]
+    pub fn with_barrier(mut self) -> Self {
+        self.barrier = true;
+        self
+    }
+
+    /// Whether the event puts a sync point before its systems, see [`with_barrier`](AddSystems::with_barrier).
+    #[inline]
+    pub fn has_barrier(&self) -> bool {
+        self.barrier
+    }
+
+    /// The [`AdditionsGeneration`] the event was sent in, or `None` if it wasn't sent through [`WorldAddSystems`].
+    #[inline]
+    pub fn generation(&self) -> Option<u32> {
+        self.generation
+    }
+
+    /// The [`TypeId`] of the type whose [`register`](crate::RegisterInWorld::register) sent the event,
+    /// or `None` if it wasn't sent through [`WorldAddSystems`] during a registration. Labeled, keyed and
+    /// [`register_as`](crate::RegisterExtension::register_as) registrations don't register the type itself,
+    /// so their events are not attributed to it.
+    ///
+    /// The origins of the added systems are listed in [`RuntimeAddedSystems::origins`].
+    /// Systems can't be removed from schedules in bevy 0.14, so the systems keep running
+    /// after the type is unregistered with [`unregister_all`](crate::RegisterExtension::unregister_all).
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{add_systems::RuntimeAddedSystems, prelude::*};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Ticks(u32);
+    ///
+    /// struct Clock;
+    ///
+    /// impl RegisterInWorld for Clock {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems(Update, |mut ticks: ResMut<Ticks>| ticks.0 += 1);
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<Ticks>();
+    /// app.register::<Clock>();
+    /// app.update();
+    /// assert_eq!(
+    ///     app.world().resource::<RuntimeAddedSystems>().origins(Update),
+    ///     [Some(std::any::type_name::<Clock>())],
+    /// );
+    ///
+    /// app.update();
+    /// assert_eq!(app.world().resource::<Ticks>().0, 1);
+    ///
+    /// app.world_mut().unregister_all();
+    /// app.update();
+    /// assert_eq!(app.world().resource::<Ticks>().0, 2);
+    /// ```
+    #[inline]
+    pub fn origin(&self) -> Option<TypeId> {
+        self.origin.map(|(id, _)| id)
+    }
+
+    /// Stamps the event with the current [`AdditionsGeneration`] and attributes it to the registration
+    /// in progress, unless it was stamped when it was sent through commands.
+    fn stamped(mut self, world: &World) -> Self {
+        if self.generation.is_none() {
+            self.generation = Some(world.get_resource().map_or(0, AdditionsGeneration::get));
+            self.origin = world
+                .get_resource::<RegisteredTypes>()
+                .and_then(RegisteredTypes::current_registration);
+        }
+        self
+    }
+
+    /// Counts the event in [`PendingSystemAdditions`], if it exists. Only counted events
+    /// are subtracted from it when they are consumed.
+    fn counted(mut self, pending: Option<Mut<PendingSystemAdditions>>) -> Self {
+        if let Some(mut pending) = pending {
+            pending.add(self.label);
+            self.counted = true;
+        }
+        self
+    }
+
+    /// Returns `true` if the event was sent before the `current` generation was started.
+    fn is_stale(&self, current: Option<&AdditionsGeneration>) -> bool {
+        self.generation.is_some_and(|generation| generation < current.map_or(0, AdditionsGeneration::get))
+    }
+
+    /// The amount of systems that will be added.
+    pub fn systems_len(&self) -> usize {
+        fn len(configs: &SystemConfigs) -> usize {
+            match configs {
+                NodeConfigs::NodeConfig(_) => 1,
+                NodeConfigs::Configs { configs, .. } => configs.iter().map(len).sum(),
+            }
+        }
+
+        len(&self.configs)
+    }
+}
+
+/// Error of requesting to add systems.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddSystemsError {
+    /// Tried to add systems to [`AddingSystems`] schedule.
+    #[error("Trying to add systems to `AddingSystems` schedule using `AddSystems` event. This is not allowed since `AddSystems` events are consumed during `AddingSystems` schedule.")]
+    AddingSystemsSchedule,
+    /// [`ConsumableEvents<AddSystems>`] doesn't exist in the world.
+    #[error("`ConsumableEvents<AddSystems>` doesn't exist in the world. Add `RegisterInWorldPlugin` or initialize the resource manually.")]
+    MissingEventBuffer,
+    /// [`SystemsAddedOnce`] doesn't exist in the world.
+    #[error("`SystemsAddedOnce` doesn't exist in the world. Add `RegisterInWorldPlugin` or initialize the resource manually.")]
+    MissingAddedOnce,
+    /// Tried to add systems to a schedule forbidden by [`AddSystemsPolicy`].
+    #[error("Trying to add systems to {0:?}, which is forbidden by `AddSystemsPolicy`.")]
+    ForbiddenSchedule(InternedScheduleLabel),
+}
+
+/// What happened to a request to add systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddOutcome {
+    /// [`AddSystems`] event was sent, or is going to be sent when the commands are applied.
+    /// For deferred [`add_systems_once`](WorldAddSystems::add_systems_once), the `key` is only checked
+    /// when the commands are applied, so the event may not be sent after all.
+    Queued,
+    /// Systems were already added with the same key, so nothing was sent.
+    Deduplicated,
+    /// Nothing was sent because of the error.
+    Failed(AddSystemsError),
+}
+
+impl AddOutcome {
+    /// Returns `true` if the event was sent.
+    #[inline]
+    pub fn is_queued(self) -> bool {
+        self == AddOutcome::Queued
+    }
+
+    /// Returns the error, if the request failed.
+    #[inline]
+    pub fn error(self) -> Option<AddSystemsError> {
+        match self {
+            AddOutcome::Failed(err) => Some(err),
+            _ => None,
+        }
     }
 }
 
+impl From<Result<(), AddSystemsError>> for AddOutcome {
+    fn from(result: Result<(), AddSystemsError>) -> Self {
+        match result {
+            Ok(()) => AddOutcome::Queued,
+            Err(err) => AddOutcome::Failed(err),
+        }
+    }
+}
+
+/// Statistics of adding systems by [`add_requested_systems`], [`drain_system_additions`]
+/// and `add_requested_fixed_systems`, updated if the resource exists.
+/// Initialized by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{add_systems::SystemAdditionStats, prelude::*};
+///
+/// fn a() {}
+/// fn b() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.world_mut().add_systems(Update, (a, b));
+/// app.update();
+///
+/// let stats = app.world().resource::<SystemAdditionStats>();
+/// assert_eq!(stats.last_run, 2);
+/// assert_eq!(stats.frame, 2);
+/// assert_eq!(stats.total, 2);
+///
+/// app.update();
+/// let stats = app.world().resource::<SystemAdditionStats>();
+/// assert_eq!(stats.last_run, 0);
+/// assert_eq!(stats.frame, 0);
+/// assert_eq!(stats.total, 2);
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct SystemAdditionStats {
+    /// Systems added during the last drain of the requested systems.
+    pub last_run: usize,
+    /// Systems added during all the drains since the start of the frame,
+    /// reset in [`First`](bevy_app::First) by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+    pub frame: usize,
+    /// Systems added during all the drains of the requested systems.
+    pub total: usize,
+}
+
 /// Consumes all [`AddSystems`] events, and adds it to the needed schedules.
+/// The name of this system is `bevy_register_in_world::add_systems::add_requested_systems`,
+/// and in [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin)
+/// it's in [`RegisterInWorldSet::ApplyAddSystems`].
 /// This should *only* run during [`AddingSystems`] schedules. 
 /// If you're not using [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin),
 /// add this system to the [`AddingSystems`] schedule, and not 
 /// 
 /// Note that events should be sent using [`ConsumableEventWriter`](bevy_consumable_event::ConsumableEventWriter).
 /// 
-pub fn add_requested_systems(
-    mut events: ConsumableEventReader<AddSystems>,
-    mut schedules: ResMut<Schedules>,
+/// Events are applied in the order of their [priority](AddSystems::with_priority),
+/// and then strictly in the order they were sent in, one at a time.
+/// Events targeting the schedule in [`AddingSystemsLabel`] are skipped with an error,
+/// and if applying an event panics, the error is logged and the remaining events are still applied,
+/// see below for what it leaves behind.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct MySchedule;
+///
+/// fn first() {}
+/// fn second() {}
+/// fn third() {}
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// let world = harness.world_mut();
+/// world.add_systems(MySchedule, first);
+/// world.add_systems(MySchedule, second);
+/// world.add_systems(MySchedule, third);
+///
+/// harness.flush_registrations();
+///
+/// let schedules = harness.world().resource::<Schedules>();
+/// let names: Vec<_> = schedules.get(MySchedule).unwrap()
+///     .graph()
+///     .systems()
+///     .map(|(_, system, _)| system.name())
+///     .collect();
+/// assert!(names[0].ends_with("first"));
+/// assert!(names[1].ends_with("second"));
+/// assert!(names[2].ends_with("third"));
+/// ```
+///
+/// A failing event is skipped with an error naming the schedule, and the type whose registration requested
+/// the systems if it's [tracked](AddSystems::origin), and the remaining events are still applied:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use bevy_utils::tracing::Level;
+/// use bevy_register_in_world::{
+///     add_systems::AddingSystemsLabel,
+///     prelude::*,
+///     test_utils::{capture_logs, RegistrationTestHarness},
+/// };
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct MySchedule;
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct CustomAdding;
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(CustomAdding, || {});
+///     }
+/// }
+///
+/// fn before_foo() {}
+/// fn after_foo() {}
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.world_mut().add_systems(MySchedule, before_foo);
+/// harness.register::<Foo>();
+/// harness.world_mut().add_systems(MySchedule, after_foo);
+///
+/// // Systems are added during `CustomAdding` from now on, so the systems requested for it are skipped
+/// harness.world_mut().insert_resource(AddingSystemsLabel(CustomAdding.intern()));
+/// let logs = capture_logs(Level::ERROR, || {
+///     harness.flush_registrations();
+/// });
+/// assert_eq!(logs.len(), 1);
+/// assert!(logs[0].contains(std::any::type_name::<Foo>()));
+///
+/// let schedules = harness.world().resource::<Schedules>();
+/// assert!(schedules.get(CustomAdding).is_none());
+/// assert_eq!(schedules.get(MySchedule).unwrap().systems_len(), 2);
+/// harness.assert_system_in_schedule(MySchedule, "before_foo");
+/// harness.assert_system_in_schedule(MySchedule, "after_foo");
+/// ```
+///
+/// A panic while an event is applied is logged the same way, but applying an event is not transactional.
+/// The panic can happen after the systems were already put into the schedule, and systems can't be removed,
+/// so the schedule can be left partially modified: with the systems of the event,
+/// but without them being recorded in [`RuntimeAddedSystems`].
+///
+/// Systems are initialized right after they are added, on the main thread, so systems that access
+/// [`NonSend`](bevy_ecs::system::NonSend) resources are supported, and are flagged in [`RuntimeAddedSystems`].
+/// Schedules that already ran are rebuilt too. If initializing the systems or rebuilding the schedule fails,
+/// an error naming the schedule is logged, instead of only a panic when the schedule runs,
+/// and the systems requested for other schedules are still added and initialized.
+/// The failure is still fatal for that schedule: systems can't be removed from it,
+/// so the broken systems stay there, and the schedule panics the next time it runs.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct MySchedule;
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct Broken;
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Resource, Default)]
+/// struct Ran(u32);
+///
+/// // Panics when initialized, because the queries conflict
+/// fn conflicting(_a: Query<&mut Health>, _b: Query<&mut Health>) {}
+///
+/// fn before_broken(mut ran: ResMut<Ran>) {
+///     ran.0 += 1;
+/// }
+///
+/// fn after_broken(mut ran: ResMut<Ran>) {
+///     ran.0 += 1;
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// let world = harness.world_mut();
+/// world.init_resource::<Ran>();
+/// world.add_systems(MySchedule, before_broken);
+/// world.add_systems(Broken, conflicting);
+/// world.add_systems(MySchedule, after_broken);
+///
+/// harness.flush_registrations();
+/// harness.assert_system_in_schedule(MySchedule, "before_broken");
+/// harness.assert_system_in_schedule(MySchedule, "after_broken");
+/// harness.run_schedule(MySchedule);
+/// assert_eq!(harness.world().resource::<Ran>().0, 2);
+///
+/// let broken = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+///     harness.run_schedule(Broken);
+/// }));
+/// assert!(broken.is_err());
+/// ```
+///
+/// If [`Schedules`] doesn't exist, a warning is logged and the events are left unconsumed,
+/// so they are applied on the next run, once the resource is back.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_ecs::system::RunSystemOnce;
+/// use bevy_register_in_world::{add_systems::add_requested_systems, prelude::*, test_utils::RegistrationTestHarness};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct MySchedule;
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// let world = harness.world_mut();
+/// world.add_systems(MySchedule, || {});
+///
+/// let schedules = world.remove_resource::<Schedules>().unwrap();
+/// world.run_system_once(add_requested_systems);
+///
+/// world.insert_resource(schedules);
+/// world.run_system_once(add_requested_systems);
+/// world.run_system_once(add_requested_systems);
+///
+/// let schedules = world.resource::<Schedules>();
+/// assert_eq!(schedules.get(MySchedule).unwrap().systems_len(), 1);
+/// ```
+pub fn add_requested_systems(world: &mut World, state: &mut SystemState<AddRequestedSystemsParams>) {
+    apply_requested_systems(world, state, |_, _| true);
+}
+
+/// Adds the requested systems right away, the same as [`add_requested_systems`] does during [`AddingSystems`].
+/// Call it from an exclusive system, when systems requested earlier in the frame should run later in the same frame.
+///
+/// A schedule is removed from [`Schedules`] while it runs, so systems added to it at that time would be lost
+/// when it's put back. Since it's impossible to tell a running schedule apart from a schedule that doesn't exist yet,
+/// events targeting schedules that are not in [`Schedules`] are left for [`AddingSystems`].
+/// This includes the schedule the caller runs in, and schedules that were never initialized,
+/// for example with [`App::init_schedule`](bevy_app::App::init_schedule).
+/// Requested [`ConfigureSets`](crate::configure_sets::ConfigureSets) are applied first, the same way.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{add_systems::drain_system_additions, prelude::*};
+///
+/// #[derive(Resource, Default)]
+/// struct Log(Vec<(u32, &'static str)>);
+///
+/// #[derive(Resource, Default)]
+/// struct Frame(u32);
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Wave;
+///
+/// impl RegisterInWorld for Wave {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(PostUpdate, |frame: Res<Frame>, mut log: ResMut<Log>| log.0.push((frame.0, "post_update")));
+///         world.add_systems(Update, |frame: Res<Frame>, mut log: ResMut<Log>| log.0.push((frame.0, "update")));
+///     }
+/// }
+///
+/// fn spawn_wave(world: &mut World) {
+///     if world.resource::<Frame>().0 == 1 {
+///         world.spawn(Wave);
+///         drain_system_additions(world);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.init_resource::<Log>().init_resource::<Frame>();
+/// app.init_schedule(PostUpdate);
+/// app.add_systems(First, |mut frame: ResMut<Frame>| frame.0 += 1);
+/// app.add_systems(Update, spawn_wave);
+///
+/// app.update();
+/// // `Update` was running, so its system was added during `AddingSystems`
+/// assert_eq!(app.world().resource::<Log>().0, [(1, "post_update")]);
+///
+/// app.update();
+/// let log = &app.world().resource::<Log>().0;
+/// assert!(log.contains(&(2, "update")));
+/// assert!(log.contains(&(2, "post_update")));
+/// ```
+pub fn drain_system_additions(world: &mut World) {
+    apply_set_configs(world, true);
+    let mut state = SystemState::new(world);
+    apply_requested_systems(world, &mut state, |event, schedules| schedules.contains(event.schedule()));
+}
+
+/// Parameters of [`add_requested_systems`].
+pub type AddRequestedSystemsParams = (
+    ConsumableEventReader<'static, AddSystems>,
+    Option<ResMut<'static, Schedules>>,
+    Option<ResMut<'static, SystemAdditionStats>>,
+    Option<ResMut<'static, RuntimeAddedSystems>>,
+    Option<ResMut<'static, PendingSystemAdditions>>,
+    Option<Res<'static, AddingSystemsLabel>>,
+    Option<Res<'static, AdditionsGeneration>>,
+);
+
+/// Applies the requested systems the `filter` accepts, leaving the other events for later drains,
+/// and initializes the schedules that got systems.
+pub(crate) fn apply_requested_systems(
+    world: &mut World,
+    state: &mut SystemState<AddRequestedSystemsParams>,
+    filter: impl Fn(&AddSystems, &Schedules) -> bool,
 ) {
-    for AddSystems(schedule, systems) in events.read_and_consume_all() {
+    let (mut events, schedules, stats, runtime_added, pending, adding_systems_label, generation) =
+        state.get_mut(world);
+    let Some(mut schedules) = schedules else {
+        if events.read().next().is_some() {
+            warn!("`Schedules` doesn't exist, adding the requested systems is postponed until it's back");
+        }
+        return;
+    };
+
+    let requested: Vec<_> = events
+        .read()
+        .filter(|event| filter(event, &schedules))
+        .map(Consume::consume)
+        .collect();
+    let mut touched = Vec::new();
+    for event in &requested {
+        if !touched.contains(&event.schedule()) {
+            touched.push(event.schedule());
+        }
+    }
+    let added = apply_add_systems(
+        requested.into_iter(),
+        &mut schedules,
+        runtime_added,
+        pending,
+        adding_systems_label.as_deref(),
+        generation.as_deref(),
+    );
+
+    if let Some(mut stats) = stats {
+        stats.last_run = added;
+        stats.frame += added;
+        stats.total += added;
+    }
+
+    for schedule in touched {
+        initialize_added_systems(world, schedule);
+    }
+}
+
+/// Initializes the systems added to the `schedule`, so errors are reported when the systems are added
+/// instead of a panic when the schedule runs, and flags the ones that are not [`Send`] in [`RuntimeAddedSystems`].
+/// The schedule is rebuilt only if it was built before, so schedules that never ran are still built on their first run.
+///
+/// Called from an exclusive system, so on the main thread, the same as the executor does it.
+fn initialize_added_systems(world: &mut World, schedule: InternedScheduleLabel) {
+    let non_send = world.try_schedule_scope(schedule, |world, initialized| {
+        let built = initialized.systems().is_ok();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            initialized.graph_mut().initialize(world);
+            if built {
+                initialized.initialize(world)
+            } else {
+                Ok(())
+            }
+        }));
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => {
+                error!("Systems added to {schedule:?} at runtime make it fail to build: {err}");
+                return None;
+            }
+            Err(_) => {
+                error!("Initializing systems added to {schedule:?} at runtime panicked");
+                return None;
+            }
+        }
+
+        let non_send: Vec<_> = match initialized.systems() {
+            Ok(systems) => systems
+                .filter(|(_, system)| !system.is_send())
+                .map(|(_, system)| system.name())
+                .collect(),
+            Err(_) => initialized
+                .graph()
+                .systems()
+                .filter(|(_, system, _)| !system.is_send())
+                .map(|(_, system, _)| system.name())
+                .collect(),
+        };
+        Some(non_send)
+    });
+
+    let Ok(Some(non_send)) = non_send else {
+        return;
+    };
+    if let Some(mut runtime_added) = world.get_resource_mut::<RuntimeAddedSystems>() {
+        let runtime_added = &mut *runtime_added;
+        let flagged = runtime_added
+            .get(schedule)
+            .iter()
+            .filter(|name| non_send.contains(name))
+            .cloned()
+            .collect();
+        runtime_added.non_send.insert(schedule, flagged);
+    }
+}
+
+/// Adds the systems from the `events` to the `schedules`, recording them in `runtime_added`.
+/// Returns the amount of added systems.
+///
+/// Every event is applied in isolation: events targeting the [`AddingSystemsLabel`] schedule are skipped,
+/// and if applying an event panics, an error naming the schedule and the origin of the event is logged
+/// and the next event is applied. The schedule may already contain some of the systems of the panicking event.
+/// The panic is still reported by the panic hook, and it's fatal if panics abort.
+/// Events sent before the current [`AdditionsGeneration`] are dropped.
+fn apply_add_systems(
+    events: impl Iterator<Item = AddSystems>,
+    schedules: &mut Schedules,
+    mut runtime_added: Option<ResMut<RuntimeAddedSystems>>,
+    mut pending: Option<ResMut<PendingSystemAdditions>>,
+    adding_systems_label: Option<&AddingSystemsLabel>,
+    generation: Option<&AdditionsGeneration>,
+) -> usize {
+    let mut events: Vec<_> = events.collect();
+    // Stable, so events with the same priority keep the order they were sent in
+    events.sort_by_key(|event| Reverse(event.priority()));
+
+    let mut added = 0;
+    for event in events {
+        let schedule = event.schedule();
+        if let Some(pending) = pending.as_deref_mut().filter(|_| event.counted) {
+            pending.remove(schedule);
+        }
+        if adding_systems_label.is_some_and(|label| label.0 == schedule) {
+            error!(
+                "Skipping systems for {schedule:?}{}: {}",
+                requested_by(event.origin),
+                AddSystemsError::AddingSystemsSchedule,
+            );
+            continue;
+        }
+        if event.is_stale(generation) {
+            debug!(
+                "Dropping systems for {schedule:?} requested in the additions generation {:?}",
+                event.generation()
+            );
+            continue;
+        }
+
+        let origin = event.origin;
+        let runtime_added = runtime_added.as_deref_mut();
+        match catch_unwind(AssertUnwindSafe(|| apply_event(event, schedules, runtime_added))) {
+            Ok(systems_len) => added += systems_len,
+            Err(_) => error!(
+                "Adding systems to {schedule:?}{} panicked, skipping the rest of them",
+                requested_by(origin),
+            ),
+        }
+    }
+    added
+}
+
+/// Names the type whose registration requested the systems in errors, if it's tracked.
+fn requested_by(origin: Option<(TypeId, &'static str)>) -> String {
+    origin.map_or_else(String::new, |(_, name)| format!(" (requested by `{name}`)"))
+}
+
+/// Adds the systems from the `event` to the `schedules`. Returns the amount of added systems.
+fn apply_event(
+    event: AddSystems,
+    schedules: &mut Schedules,
+    runtime_added: Option<&mut RuntimeAddedSystems>,
+) -> usize {
+    let systems_len = event.systems_len();
+    let AddSystems {
+        label: schedule,
+        configs: systems,
+        barrier,
+        origin,
+        ..
+    } = event;
+    #[cfg(feature = "trace")]
+    let _span = info_span!("add_systems", schedule = ?schedule, systems = systems_len).entered();
+    debug!("Adding {systems_len} systems to {schedule:?}");
+
+    let mut generation = runtime_added
+        .as_deref()
+        .map_or(0, |runtime_added| runtime_added.generation(schedule));
+    if barrier {
+        let previous = RuntimeAddedSet(generation);
+        generation += 1;
+        schedules.add_systems(
+            schedule,
+            apply_deferred.after(previous).before(RuntimeAddedSet(generation)),
+        );
+    }
+    let systems = systems.in_set(RuntimeAddedSet(generation));
+
+    let Some(runtime_added) = runtime_added else {
         schedules.add_systems(schedule, systems);
+        return systems_len;
+    };
+    runtime_added.generations.insert(schedule, generation);
+
+    // Systems that are not initialized yet are still in the graph,
+    // so the new ones are the ones that weren't there before
+    let new_systems = |schedules: &Schedules| -> Vec<(NodeId, Cow<'static, str>)> {
+        schedules.get(schedule).map_or_else(Vec::new, |schedule| {
+            schedule.graph().systems().map(|(id, system, _)| (id, system.name())).collect()
+        })
+    };
+    let before = new_systems(schedules);
+    schedules.add_systems(schedule, systems);
+    let after = new_systems(schedules);
+
+    let names = runtime_added.systems.entry(schedule).or_default();
+    let len = names.len();
+    names.extend(
+        after
+            .into_iter()
+            .filter(|(id, _)| !before.iter().any(|(before, _)| before == id))
+            .map(|(_, name)| name),
+    );
+    let added = names.len() - len;
+    runtime_added
+        .origins
+        .entry(schedule)
+        .or_default()
+        .extend(std::iter::repeat(origin.map(|(_, name)| name)).take(added));
+    systems_len
+}
+
+/// Generation of the [`AddSystems`] events. Events sent through [`WorldAddSystems`] are stamped with it,
+/// and events from a previous generation are dropped instead of applied.
+/// Events sent directly to [`ConsumableEvents<AddSystems>`] are not stamped, and are always applied.
+///
+/// The generation is bumped only explicitly, with [`WorldAddSystems::invalidate_pending_additions`].
+/// Use it when the schedules are rebuilt, so systems requested for the old schedules don't end up in the new ones.
+/// Initialized by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdditionsGeneration(u32);
+
+impl AdditionsGeneration {
+    /// The current generation.
+    #[inline]
+    pub fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+/// Consumes all the [`AddSystems`] events without applying them, and resets [`PendingSystemAdditions`].
+/// Returns the amount of dropped events.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{
+///     add_systems::{clear_pending_system_additions, pending_additions_count},
+///     prelude::*,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+///
+/// app.world_mut().add_systems(Update, || {});
+/// app.world_mut().add_systems(PostUpdate, || {});
+/// assert_eq!(clear_pending_system_additions(app.world_mut()), 2);
+/// assert_eq!(pending_additions_count(app.world()), 0);
+///
+/// app.update();
+/// let schedules = app.world().resource::<Schedules>();
+/// assert_eq!(schedules.get(Update).map_or(0, Schedule::systems_len), 0);
+/// ```
+pub fn clear_pending_system_additions(world: &mut World) -> usize {
+    if let Some(mut pending) = world.get_resource_mut::<PendingSystemAdditions>() {
+        pending.counts.clear();
+    }
+    let Some(mut events) = world.get_resource_mut::<ConsumableEvents<AddSystems>>() else {
+        return 0;
+    };
+    let dropped = events.read().map(Consume::consume).count();
+    debug!("Dropped {dropped} pending system additions");
+    dropped
+}
+
+/// Amounts of [`AddSystems`] events that were sent, but not applied yet, by schedule.
+/// Only the events sent through [`WorldAddSystems`] are counted, events sent directly to
+/// [`ConsumableEvents<AddSystems>`] are not. Updated if the resource exists.
+/// Initialized by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{
+///     add_systems::{no_pending_system_additions, pending_additions_count, PendingSystemAdditions},
+///     prelude::*,
+/// };
+///
+/// #[derive(Resource, Default)]
+/// struct Snapshots(u32);
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.init_resource::<Snapshots>();
+/// app.add_systems(
+///     Update,
+///     (|mut snapshots: ResMut<Snapshots>| snapshots.0 += 1).run_if(no_pending_system_additions()),
+/// );
+///
+/// app.world_mut().add_systems(Update, || {});
+/// assert_eq!(pending_additions_count(app.world()), 1);
+/// assert_eq!(app.world().resource::<PendingSystemAdditions>().get(Update), 1);
+///
+/// // Systems are added after `Update`
+/// app.update();
+/// assert_eq!(pending_additions_count(app.world()), 0);
+/// assert_eq!(app.world().resource::<Snapshots>().0, 0);
+///
+/// app.update();
+/// assert_eq!(app.world().resource::<Snapshots>().0, 1);
+/// ```
+///
+/// Events sent directly are not subtracted when they are applied either, so they don't affect the counts
+/// of the events sent through [`WorldAddSystems`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// use bevy_consumable_event::ConsumableEvents;
+/// use bevy_register_in_world::{
+///     add_systems::{drain_system_additions, PendingSystemAdditions},
+///     prelude::*,
+///     test_utils::RegistrationTestHarness,
+/// };
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct Present;
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct Missing;
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// let world = harness.world_mut();
+/// world.add_schedule(Schedule::new(Present));
+///
+/// world.add_systems(Missing, || {});
+/// world.resource_mut::<ConsumableEvents<AddSystems>>().send(AddSystems::new(Present, || {}));
+/// world.resource_mut::<ConsumableEvents<AddSystems>>().send(AddSystems::new(Missing, || {}));
+/// assert_eq!(world.resource::<PendingSystemAdditions>().total(), 1);
+///
+/// // Only the event for `Present` is applied
+/// drain_system_additions(world);
+/// assert_eq!(world.resource::<PendingSystemAdditions>().get(Missing), 1);
+///
+/// harness.flush_registrations();
+/// assert!(harness.world().resource::<PendingSystemAdditions>().is_empty());
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct PendingSystemAdditions {
+    counts: HashMap<InternedScheduleLabel, usize>,
+}
+
+impl PendingSystemAdditions {
+    /// Amount of pending events for the `schedule`.
+    pub fn get(&self, schedule: impl ScheduleLabel) -> usize {
+        self.counts.get(&schedule.intern()).copied().unwrap_or(0)
+    }
+
+    /// Amount of pending events for all the schedules.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns `true` if there are no pending events.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    fn add(&mut self, schedule: InternedScheduleLabel) {
+        *self.counts.entry(schedule).or_default() += 1;
+    }
+
+    fn remove(&mut self, schedule: InternedScheduleLabel) {
+        if let Some(count) = self.counts.get_mut(&schedule) {
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(&schedule);
+            }
+        }
+    }
+}
+
+/// Amount of [`AddSystems`] events that are not applied yet, see [`PendingSystemAdditions`].
+/// Returns `0` if the resource doesn't exist.
+pub fn pending_additions_count(world: &World) -> usize {
+    world
+        .get_resource::<PendingSystemAdditions>()
+        .map_or(0, PendingSystemAdditions::total)
+}
+
+/// Run condition that is `true` if there are no [`AddSystems`] events that are not applied yet,
+/// see [`PendingSystemAdditions`].
+pub fn no_pending_system_additions(
+) -> impl FnMut(Option<Res<PendingSystemAdditions>>) -> bool + Clone {
+    |pending: Option<Res<PendingSystemAdditions>>| pending.map_or(true, |pending| pending.is_empty())
+}
+
+/// Names of the systems that were added by [`add_requested_systems`], grouped by schedule. 
+/// Updated if the resource exists. Initialized by 
+/// [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+#[derive(Resource, Default, Debug)]
+pub struct RuntimeAddedSystems {
+    systems: HashMap<InternedScheduleLabel, Vec<Cow<'static, str>>>,
+    origins: HashMap<InternedScheduleLabel, Vec<Option<&'static str>>>,
+    non_send: HashMap<InternedScheduleLabel, Vec<Cow<'static, str>>>,
+    generations: HashMap<InternedScheduleLabel, u32>,
+}
+
+impl RuntimeAddedSystems {
+    /// Names of the systems added to the `schedule`, in the order they were added in.
+    pub fn get(&self, schedule: impl ScheduleLabel) -> &[Cow<'static, str>] {
+        self.systems
+            .get(&schedule.intern())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Names of the types the systems added to the `schedule` are attributed to, see [`AddSystems::origin`],
+    /// at the same indices as the systems in [`get`](RuntimeAddedSystems::get).
+    pub fn origins(&self, schedule: impl ScheduleLabel) -> &[Option<&'static str>] {
+        self.origins
+            .get(&schedule.intern())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Names of the systems added to the `schedule` that are not [`Send`], because they access
+    /// [`NonSend`](bevy_ecs::system::NonSend) resources, in the order they were added in.
+    /// The executor runs them on the main thread.
+    ///
+    /// Only systems added by [`add_requested_systems`] and [`drain_system_additions`] are flagged,
+    /// since they initialize the schedule right after adding the systems.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// # use std::rc::Rc;
+    /// use bevy_register_in_world::{add_systems::RuntimeAddedSystems, prelude::*};
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct MySchedule;
+    ///
+    /// // Not `Send`, like handles of a windowing library
+    /// struct WindowHandle(Rc<u32>);
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Seen(Vec<u32>);
+    ///
+    /// fn read_handle(handle: NonSend<WindowHandle>, mut seen: ResMut<Seen>) {
+    ///     seen.0.push(*handle.0);
+    /// }
+    ///
+    /// fn send_system() {}
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<Seen>();
+    /// app.insert_non_send_resource(WindowHandle(Rc::new(7)));
+    /// app.init_schedule(MySchedule);
+    ///
+    /// app.world_mut().add_systems(Update, (read_handle, send_system));
+    /// app.world_mut().add_systems(MySchedule, read_handle);
+    /// app.update();
+    ///
+    /// let runtime_added = app.world().resource::<RuntimeAddedSystems>();
+    /// assert_eq!(runtime_added.get(Update).len(), 2);
+    /// assert_eq!(runtime_added.non_send(Update).len(), 1);
+    /// assert!(runtime_added.non_send(Update)[0].ends_with("read_handle"));
+    /// assert!(runtime_added.non_send(MySchedule)[0].ends_with("read_handle"));
+    ///
+    /// app.update();
+    /// app.world_mut().run_schedule(MySchedule);
+    /// assert_eq!(app.world().resource::<Seen>().0, [7, 7]);
+    /// ```
+    pub fn non_send(&self, schedule: impl ScheduleLabel) -> &[Cow<'static, str>] {
+        self.non_send
+            .get(&schedule.intern())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The [`RuntimeAddedSet`] the systems added to the `schedule` without a [barrier](AddSystems::with_barrier) are put in.
+    pub fn generation(&self, schedule: impl ScheduleLabel) -> u32 {
+        self.generations.get(&schedule.intern()).copied().unwrap_or(0)
+    }
+
+    /// Iterates over schedules and names of the systems added to them, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &[Cow<'static, str>])> {
+        self.systems
+            .iter()
+            .map(|(schedule, systems)| (*schedule, systems.as_slice()))
+    }
+}
+
+/// Logs every schedule that got systems since the last run, with the amount of them, at `debug` level.
+/// Added to [`AddingSystems`] after [`RegisterInWorldSet::ApplyAddSystems`]
+/// by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_utils::tracing::Level;
+/// use bevy_register_in_world::{prelude::*, test_utils::capture_logs};
+///
+/// fn a() {}
+/// fn b() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.world_mut().add_systems(Update, (a, b));
+///
+/// let logs = capture_logs(Level::DEBUG, || app.update());
+/// assert!(logs.iter().any(|log| log == "Added 2 systems to Update"));
+/// ```
+#[cfg(feature = "debug-add-systems")]
+pub fn log_added_systems(
+    runtime_added: Res<RuntimeAddedSystems>,
+    mut logged: Local<HashMap<InternedScheduleLabel, usize>>,
+) {
+    if !runtime_added.is_changed() {
+        return;
+    }
+
+    for (schedule, systems) in runtime_added.iter() {
+        let logged = logged.entry(schedule).or_default();
+        if systems.len() > *logged {
+            debug!("Added {} systems to {schedule:?}", systems.len() - *logged);
+        }
+        *logged = systems.len();
+    }
+}
+
+/// Keys of the systems added with [`WorldAddSystems::add_systems_once`].
+/// Initialized by [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin).
+#[derive(Resource, Default, Debug)]
+pub struct SystemsAddedOnce {
+    keys: KeySet,
+}
+
+impl SystemsAddedOnce {
+    /// Returns `true` if systems were already added with the `key`.
+    #[inline]
+    pub fn contains<K: Hash + Eq + 'static>(&self, key: &K) -> bool {
+        self.keys.contains(TypeId::of::<Self>(), key)
+    }
+
+    /// Returns `true` if the key wasn't there before.
+    fn insert<K: Hash + Eq + Send + Sync + 'static>(&mut self, key: K) -> bool {
+        self.keys.insert(TypeId::of::<Self>(), key).is_some()
     }
 }
 
@@ -52,18 +1572,733 @@ pub fn add_requested_systems(
 pub trait WorldAddSystems {
     /// Sends [`AddSystems`] event.
     fn add_systems<M>(&mut self, schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>);
+
+    /// Sends [`AddSystems`] event, returning an error instead of panicking.
+    /// Unlike [`add_systems`](WorldAddSystems::add_systems), implementations for [`World`]
+    /// and [`DeferredWorld`] don't initialize [`ConsumableEvents<AddSystems>`], and return
+    /// [`AddSystemsError::MissingEventBuffer`] if it doesn't exist.
+    ///
+    /// By default only checks the schedule, and then calls [`add_systems`](WorldAddSystems::add_systems).
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_register_in_world::{
+    ///     add_systems::{AddSystemsError, AddingSystems},
+    ///     prelude::*,
+    /// };
+    ///
+    /// fn system() {}
+    ///
+    /// let mut world = World::new();
+    /// assert_eq!(
+    ///     world.try_add_systems(Update, system),
+    ///     Err(AddSystemsError::MissingEventBuffer),
+    /// );
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// let world = app.world_mut();
+    /// assert_eq!(
+    ///     world.try_add_systems(AddingSystems, system),
+    ///     Err(AddSystemsError::AddingSystemsSchedule),
+    /// );
+    /// assert_eq!(world.try_add_systems(Update, system), Ok(()));
+    /// ```
+    fn try_add_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> Result<(), AddSystemsError> {
+        self.add([AddSystems::try_new(schedule, systems)?]);
+        Ok(())
+    }
+
+    /// Sends [`AddSystems`] event for the schedule [resolved](DynScheduleLabel::resolve) from the `name`.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_register_in_world::{add_systems::DynScheduleLabel, prelude::*};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<Log>();
+    /// app.add_schedule(Schedule::new(DynScheduleLabel::new("my_mod_phase")));
+    ///
+    /// let world = app.world_mut();
+    /// world.add_systems_by_name("update", |mut log: ResMut<Log>| log.0.push("update"));
+    /// world.add_systems_by_name("my_mod_phase", |mut log: ResMut<Log>| log.0.push("my_mod_phase"));
+    ///
+    /// app.update();
+    /// app.update();
+    /// app.world_mut().run_schedule(DynScheduleLabel::new("my_mod_phase"));
+    /// assert_eq!(app.world().resource::<Log>().0, ["update", "my_mod_phase"]);
+    /// ```
+    fn add_systems_by_name<M>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        systems: impl IntoSystemConfigs<M>,
+    ) {
+        self.add_systems(DynScheduleLabel::resolve(name), systems);
+    }
+
+    /// Sends [`AddSystems`] event only the first time the `key` is seen, tracked in [`SystemsAddedOnce`].
+    /// Use it for systems shared between all the instantiations of a generic type,
+    /// so they are not added once per instantiation.
+    ///
+    /// Returns [`AddOutcome::Deduplicated`] if the `key` was already seen, and [`AddOutcome::Queued`] otherwise.
+    /// When [`SystemsAddedOnce`] doesn't exist yet, the implementation for [`DeferredWorld`] defers the check
+    /// to commands and returns [`AddOutcome::Queued`] before it, so the systems are still not sent
+    /// if the `key` is recorded by the time the commands are applied.
+    ///
+    /// Requests for the schedule in [`AddingSystemsLabel`] and the schedules forbidden by [`AddSystemsPolicy`]
+    /// are handled according to the policy, the same as with [`add_systems`](WorldAddSystems::add_systems):
+    /// with [`ForbiddenSchedulePolicy::Panic`], the default, this panics. With [`ForbiddenSchedulePolicy::Reject`],
+    /// the error is logged, [`AddOutcome::Failed`] is returned and the `key` is not recorded.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::marker::PhantomData;
+    /// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct MySchedule;
+    ///
+    /// struct GenericThing<T>(PhantomData<T>);
+    ///
+    /// impl<T: Send + Sync + 'static> RegisterInWorld for GenericThing<T> {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems_once("common_setup", MySchedule, common_setup);
+    ///         world.add_systems(MySchedule, generic_system::<T>);
+    ///     }
+    /// }
+    ///
+    /// fn common_setup() {}
+    /// fn generic_system<T>() {}
+    ///
+    /// let mut harness = RegistrationTestHarness::new();
+    /// harness
+    ///     .register::<GenericThing<u8>>()
+    ///     .register::<GenericThing<u16>>()
+    ///     .flush_registrations();
+    ///
+    /// let schedules = harness.world().resource::<Schedules>();
+    /// assert_eq!(schedules.get(MySchedule).unwrap().systems_len(), 3);
+    /// ```
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// use bevy_register_in_world::{
+    ///     add_systems::{AddOutcome, AddSystemsError, AddSystemsPolicy, ForbiddenSchedulePolicy},
+    ///     prelude::*,
+    ///     test_utils::RegistrationTestHarness,
+    /// };
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct NetworkSend;
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct MySchedule;
+    ///
+    /// fn system() {}
+    ///
+    /// let mut harness = RegistrationTestHarness::new();
+    /// let world = harness.world_mut();
+    /// world.insert_resource(AddSystemsPolicy {
+    ///     on_forbidden_schedule: ForbiddenSchedulePolicy::Reject,
+    ///     forbidden: vec![NetworkSend.intern()],
+    /// });
+    ///
+    /// assert_eq!(
+    ///     world.add_systems_once("setup", NetworkSend, system),
+    ///     AddOutcome::Failed(AddSystemsError::ForbiddenSchedule(NetworkSend.intern())),
+    /// );
+    /// assert_eq!(world.add_systems_once("setup", MySchedule, system), AddOutcome::Queued);
+    /// ```
+    fn add_systems_once<K: Hash + Eq + Send + Sync + 'static, M>(
+        &mut self,
+        key: K,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> AddOutcome;
+
+    /// Same as [`add_systems_once`](WorldAddSystems::add_systems_once), but reports errors
+    /// instead of panicking or falling back to commands, like [`try_add_systems`](WorldAddSystems::try_add_systems).
+    /// The `key` is recorded only if the event was sent.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_register_in_world::{
+    ///     add_systems::{AddOutcome, AddSystemsError, AddingSystems},
+    ///     prelude::*,
+    /// };
+    ///
+    /// fn system() {}
+    ///
+    /// let mut world = World::new();
+    /// assert_eq!(
+    ///     world.try_add_systems_once("setup", Update, system),
+    ///     AddOutcome::Failed(AddSystemsError::MissingEventBuffer),
+    /// );
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// let world = app.world_mut();
+    /// assert_eq!(
+    ///     world.try_add_systems_once("setup", AddingSystems, system),
+    ///     AddOutcome::Failed(AddSystemsError::AddingSystemsSchedule),
+    /// );
+    /// assert_eq!(world.try_add_systems_once("setup", Update, system), AddOutcome::Queued);
+    /// assert_eq!(world.try_add_systems_once("setup", Update, system), AddOutcome::Deduplicated);
+    /// assert_eq!(world.add_systems_once("setup", Update, system), AddOutcome::Deduplicated);
+    /// assert_eq!(AddOutcome::from(world.try_add_systems(Update, system)), AddOutcome::Queued);
+    /// ```
+    fn try_add_systems_once<K: Hash + Eq + Send + Sync + 'static, M>(
+        &mut self,
+        key: K,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> AddOutcome;
+
+    /// Same as [`add_systems`](WorldAddSystems::add_systems), but with a [barrier](AddSystems::with_barrier):
+    /// the systems run after the systems added to the `schedule` by earlier events,
+    /// and commands of those systems are applied before them.
+    ///
+    /// Requests for the schedule in [`AddingSystemsLabel`] and the schedules forbidden by [`AddSystemsPolicy`]
+    /// are handled according to the policy, the same as with [`add_systems`](WorldAddSystems::add_systems).
+    fn add_systems_with_barrier<M>(&mut self, schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>);
+
+    /// Sends [`AddSystems`] event adding the `systems` in the `set`, and [`ConfigureSets`](crate::configure_sets::ConfigureSets) event configuring
+    /// the set with the `set_config`, so the set's ordering and run conditions are declared together with its systems.
+    /// Sets are configured before the systems are added, so the configuration is in place
+    /// on the first run of the schedule with the systems.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// /// Public set of another crate
+    /// #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct PhysicsSet;
+    ///
+    /// #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct InputSet;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// struct Input;
+    ///
+    /// impl RegisterInWorld for Input {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems_in_set(
+    ///             Update,
+    ///             InputSet,
+    ///             |mut log: ResMut<Log>| log.0.push("input"),
+    ///             InputSet.before(PhysicsSet),
+    ///         );
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<Log>();
+    /// app.add_systems(Update, (|mut log: ResMut<Log>| log.0.push("physics")).in_set(PhysicsSet));
+    ///
+    /// app.register::<Input>();
+    /// app.update();
+    /// app.update();
+    /// assert_eq!(app.world().resource::<Log>().0, ["physics", "input", "physics"]);
+    /// ```
+    ///
+    /// Requests for the schedule in [`AddingSystemsLabel`] and the schedules forbidden by [`AddSystemsPolicy`]
+    /// are handled according to the policy, the same as with [`add_systems`](WorldAddSystems::add_systems),
+    /// and the set is not configured then.
+    fn add_systems_in_set<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl SystemSet,
+        systems: impl IntoSystemConfigs<M>,
+        set_config: impl IntoSystemSetConfigs,
+    ) where
+        Self: WorldConfigureSets;
+
+    /// Sends every [`AddSystems`] event of the `additions`, keeping their priorities.
+    /// Accepts [`SystemAddition`], so registration fragments can be built separately and combined before sending.
+    ///
+    /// Events for the schedule in [`AddingSystemsLabel`] and the schedules forbidden by [`AddSystemsPolicy`]
+    /// are handled according to the policy, the same as with [`add_systems`](WorldAddSystems::add_systems).
+    fn add(&mut self, additions: impl IntoIterator<Item = AddSystems>);
+
+    /// Starts the next [`AdditionsGeneration`], so the [`AddSystems`] events sent before are dropped
+    /// instead of applied. Use it when rebuilding the schedules wholesale.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct Gameplay;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<Log>();
+    /// app.init_schedule(Gameplay);
+    ///
+    /// // Requested for the editor setup
+    /// app.world_mut().add_systems(Gameplay, |mut log: ResMut<Log>| log.0.push("editor"));
+    ///
+    /// // Switching to play mode rebuilds the schedule
+    /// app.world_mut().invalidate_pending_additions();
+    /// app.world_mut().resource_mut::<Schedules>().insert(Schedule::new(Gameplay));
+    /// app.world_mut().add_systems(Gameplay, |mut log: ResMut<Log>| log.0.push("play"));
+    ///
+    /// app.update();
+    /// app.world_mut().run_schedule(Gameplay);
+    /// assert_eq!(app.world().resource::<Log>().0, ["play"]);
+    /// ```
+    fn invalidate_pending_additions(&mut self);
+
+    /// Returns a builder that accumulates systems for the `schedule`, and sends them all
+    /// as a single [`AddSystems`] event when [finished](AddSystemsBuilder::finish) or dropped.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_consumable_event::ConsumableEvents;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// fn a() {}
+    /// fn b() {}
+    /// fn c() {}
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         let mut builder = world.add_systems_builder(Update);
+    ///         builder.add(a).add((b, c).chain());
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<ConsumableEvents<AddSystems>>();
+    /// world.register::<Foo>();
+    ///
+    /// let mut events = world.resource_mut::<ConsumableEvents<AddSystems>>();
+    /// let sent: Vec<_> = events.read().map(|event| event.systems_len()).collect();
+    /// assert_eq!(sent, [3]);
+    /// ```
+    fn add_systems_builder(&mut self, schedule: impl ScheduleLabel) -> AddSystemsBuilder<'_, Self>
+    where
+        Self: Sized,
+    {
+        AddSystemsBuilder {
+            world: self,
+            schedule: schedule.intern(),
+            configs: Vec::new(),
+        }
+    }
+}
+
+/// [`AddSystems`] events for possibly different schedules, that are sent together
+/// with [`WorldAddSystems::add`]. Return it from functions to make reusable registration fragments,
+/// and combine fragments with [`Extend`] or by chaining their iterators.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::marker::PhantomData;
+/// use bevy_register_in_world::{add_systems::SystemAddition, prelude::*, test_utils::RegistrationTestHarness};
+///
+/// fn audio<T: Send + Sync + 'static>() -> SystemAddition {
+///     SystemAddition::new(Update, play::<T>).and(FixedUpdate, mix::<T>)
+/// }
+///
+/// fn debug_draw() -> SystemAddition {
+///     SystemAddition::new(PostUpdate, draw_gizmos)
+/// }
+///
+/// fn play<T>() {}
+/// fn mix<T>() {}
+/// fn draw_gizmos() {}
+///
+/// struct Emitter<T>(PhantomData<T>);
+///
+/// impl<T: Send + Sync + 'static> RegisterInWorld for Emitter<T> {
+///     fn register(mut world: DeferredWorld) {
+///         let mut additions = audio::<T>();
+///         additions.extend(debug_draw());
+///         world.add(additions);
+///     }
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.register::<Emitter<u8>>().flush_registrations();
+///
+/// let schedules = harness.world().resource::<Schedules>();
+/// assert_eq!(schedules.get(Update).unwrap().systems_len(), 1);
+/// assert_eq!(schedules.get(FixedUpdate).unwrap().systems_len(), 1);
+/// assert_eq!(schedules.get(PostUpdate).unwrap().systems_len(), 1);
+/// ```
+#[derive(Default)]
+pub struct SystemAddition {
+    additions: Vec<AddSystems>,
+}
+
+impl SystemAddition {
+    /// Creates the addition of `systems` to the `schedule`.
+    ///
+    /// The schedule is checked when the addition is sent with [`WorldAddSystems::add`].
+    pub fn new<M>(schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) -> Self {
+        Self::default().and(schedule, systems)
+    }
+
+    /// Adds the addition of `systems` to the `schedule`, that is sent after the previous ones.
+    ///
+    /// The schedule is checked when the addition is sent with [`WorldAddSystems::add`].
+    pub fn and<M>(mut self, schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) -> Self {
+        self.additions.push(AddSystems::unchecked(schedule, systems));
+        self
+    }
+
+    /// Amount of [`AddSystems`] events.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.additions.len()
+    }
+
+    /// Returns `true` if there are no events.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.additions.is_empty()
+    }
+}
+
+impl From<AddSystems> for SystemAddition {
+    fn from(event: AddSystems) -> Self {
+        SystemAddition {
+            additions: vec![event],
+        }
+    }
+}
+
+impl Extend<AddSystems> for SystemAddition {
+    fn extend<I: IntoIterator<Item = AddSystems>>(&mut self, iter: I) {
+        self.additions.extend(iter);
+    }
+}
+
+impl IntoIterator for SystemAddition {
+    type Item = AddSystems;
+    type IntoIter = std::vec::IntoIter<AddSystems>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.additions.into_iter()
+    }
+}
+
+/// Accumulates systems for a schedule, and sends them as a single [`AddSystems`] event
+/// when [finished](AddSystemsBuilder::finish) or dropped. Nothing is sent if no systems were added.
+/// Created by [`WorldAddSystems::add_systems_builder`].
+pub struct AddSystemsBuilder<'a, W: WorldAddSystems> {
+    world: &'a mut W,
+    schedule: InternedScheduleLabel,
+    configs: Vec<SystemConfigs>,
+}
+
+impl<W: WorldAddSystems> AddSystemsBuilder<'_, W> {
+    /// Adds the systems. They are not ordered relative to the systems from other calls.
+    pub fn add<M>(&mut self, systems: impl IntoSystemConfigs<M>) -> &mut Self {
+        self.configs.push(systems.into_configs());
+        self
+    }
+
+    /// Sends the accumulated systems. The same as dropping the builder.
+    pub fn finish(self) {}
+
+    fn send(&mut self) {
+        if self.configs.is_empty() {
+            return;
+        }
+
+        let configs = NodeConfigs::Configs {
+            configs: std::mem::take(&mut self.configs),
+            collective_conditions: Vec::new(),
+            chained: Chain::No,
+        };
+        self.world.add_systems(self.schedule, configs);
+    }
+}
+
+impl<W: WorldAddSystems> Drop for AddSystemsBuilder<'_, W> {
+    fn drop(&mut self) {
+        self.send();
+    }
 }
 
 impl WorldAddSystems for DeferredWorld<'_> {
+    /// If [`AdditionsGeneration`] doesn't exist yet, the generation is bumped through commands,
+    /// which initialize the resource first. Events sent before the commands are applied are stamped
+    /// with the generation they were sent in, so they are dropped too.
+    fn invalidate_pending_additions(&mut self) {
+        match self.get_resource_mut::<AdditionsGeneration>() {
+            Some(mut generation) => generation.0 += 1,
+            None => self.commands().add(|world: &mut World| world.invalidate_pending_additions()),
+        }
+    }
+
+    /// If [`ConsumableEvents<AddSystems>`] doesn't exist yet (for example, when registering
+    /// before [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin) was built),
+    /// the event is sent through commands, which initialize the resource first.
     fn add_systems<M>(&mut self, schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) {
-        self.resource_mut::<ConsumableEvents<AddSystems>>()
-            .send(AddSystems::new(schedule, systems));
+        match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => send_deferred(self, event),
+            Err(err) => reject(self, err),
+        }
+    }
+
+    /// Sends the event the same way as [`add_systems`](WorldAddSystems::add_systems) does.
+    fn add_systems_with_barrier<M>(&mut self, schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) {
+        match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => send_deferred(self, event.with_barrier()),
+            Err(err) => reject(self, err),
+        }
+    }
+
+    /// Sends the events the same way as [`add_systems`](WorldAddSystems::add_systems)
+    /// and [`configure_sets`](WorldConfigureSets::configure_sets) do.
+    fn add_systems_in_set<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl SystemSet,
+        systems: impl IntoSystemConfigs<M>,
+        set_config: impl IntoSystemSetConfigs,
+    ) {
+        match AddSystems::try_new_in(self, schedule, systems.in_set(set)) {
+            Ok(event) => {
+                self.configure_sets(event.schedule(), set_config);
+                send_deferred(self, event);
+            }
+            Err(err) => reject(self, err),
+        }
+    }
+
+    /// Sends the events the same way as [`add_systems`](WorldAddSystems::add_systems) does.
+    fn add(&mut self, additions: impl IntoIterator<Item = AddSystems>) {
+        for event in additions {
+            match check_schedule(self, event.schedule()) {
+                Ok(()) => send_deferred(self, event),
+                Err(err) => reject(self, err),
+            }
+        }
+    }
+
+    fn try_add_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> Result<(), AddSystemsError> {
+        let event = AddSystems::try_new_in(self, schedule, systems)?;
+        if !self.contains_resource::<ConsumableEvents<AddSystems>>() {
+            return Err(AddSystemsError::MissingEventBuffer);
+        }
+        let event = event.stamped(self).counted(self.get_resource_mut());
+        self.resource_mut::<ConsumableEvents<AddSystems>>().send(event);
+        Ok(())
+    }
+
+    /// If [`SystemsAddedOnce`] doesn't exist yet, the key is checked through commands,
+    /// which initialize the resource first, and [`AddOutcome::Queued`] is returned without knowing the result.
+    fn add_systems_once<K: Hash + Eq + Send + Sync + 'static, M>(
+        &mut self,
+        key: K,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> AddOutcome {
+        let event = match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => event,
+            Err(err) => {
+                reject(self, err);
+                return AddOutcome::Failed(err);
+            }
+        };
+        match self.get_resource::<SystemsAddedOnce>() {
+            Some(once) if once.contains(&key) => AddOutcome::Deduplicated,
+            Some(_) => {
+                self.resource_mut::<SystemsAddedOnce>().insert(key);
+                send_deferred(self, event);
+                AddOutcome::Queued
+            }
+            None => {
+                // Attributed now, while the registration that requested the systems is in progress
+                let event = event.stamped(self);
+                self.commands().add(move |world: &mut World| {
+                    if world
+                        .get_resource_or_insert_with::<SystemsAddedOnce>(Default::default)
+                        .insert(key)
+                    {
+                        send_world(world, event);
+                    }
+                });
+                AddOutcome::Queued
+            }
+        }
+    }
+
+    fn try_add_systems_once<K: Hash + Eq + Send + Sync + 'static, M>(
+        &mut self,
+        key: K,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> AddOutcome {
+        let event = match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => event,
+            Err(err) => return AddOutcome::Failed(err),
+        };
+        if self.get_resource::<ConsumableEvents<AddSystems>>().is_none() {
+            return AddOutcome::Failed(AddSystemsError::MissingEventBuffer);
+        }
+        let Some(mut once) = self.get_resource_mut::<SystemsAddedOnce>() else {
+            return AddOutcome::Failed(AddSystemsError::MissingAddedOnce);
+        };
+        if !once.insert(key) {
+            return AddOutcome::Deduplicated;
+        }
+
+        let event = event.stamped(self).counted(self.get_resource_mut());
+        self.resource_mut::<ConsumableEvents<AddSystems>>().send(event);
+        AddOutcome::Queued
     }
 }
 
 impl WorldAddSystems for World {
-    #[inline]
+    /// Initializes [`AdditionsGeneration`] if it doesn't exist yet.
+    fn invalidate_pending_additions(&mut self) {
+        self.get_resource_or_insert_with::<AdditionsGeneration>(Default::default).0 += 1;
+    }
+
+    /// Initializes [`ConsumableEvents<AddSystems>`] if it doesn't exist yet.
     fn add_systems<M>(&mut self, schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) {
-        Into::<DeferredWorld>::into(self).add_systems(schedule, systems)
+        match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => send_world(self, event),
+            Err(err) => reject(self, err),
+        }
+    }
+
+    /// Initializes [`ConsumableEvents<AddSystems>`] if it doesn't exist yet.
+    fn add_systems_with_barrier<M>(&mut self, schedule: impl ScheduleLabel, systems: impl IntoSystemConfigs<M>) {
+        match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => send_world(self, event.with_barrier()),
+            Err(err) => reject(self, err),
+        }
+    }
+
+    /// Initializes [`ConsumableEvents<AddSystems>`] and [`ConsumableEvents<ConfigureSets>`](crate::configure_sets::ConfigureSets)
+    /// if they don't exist yet.
+    fn add_systems_in_set<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl SystemSet,
+        systems: impl IntoSystemConfigs<M>,
+        set_config: impl IntoSystemSetConfigs,
+    ) {
+        match AddSystems::try_new_in(self, schedule, systems.in_set(set)) {
+            Ok(event) => {
+                self.configure_sets(event.schedule(), set_config);
+                send_world(self, event);
+            }
+            Err(err) => reject(self, err),
+        }
+    }
+
+    /// Initializes [`ConsumableEvents<AddSystems>`] if it doesn't exist yet.
+    fn add(&mut self, additions: impl IntoIterator<Item = AddSystems>) {
+        for event in additions {
+            match check_schedule(self, event.schedule()) {
+                Ok(()) => send_world(self, event),
+                Err(err) => reject(self, err),
+            }
+        }
+    }
+
+    fn try_add_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> Result<(), AddSystemsError> {
+        let event = AddSystems::try_new_in(self, schedule, systems)?;
+        if !self.contains_resource::<ConsumableEvents<AddSystems>>() {
+            return Err(AddSystemsError::MissingEventBuffer);
+        }
+        let event = event.stamped(self).counted(self.get_resource_mut());
+        self.resource_mut::<ConsumableEvents<AddSystems>>().send(event);
+        Ok(())
+    }
+
+    /// Initializes [`SystemsAddedOnce`] if it doesn't exist yet.
+    fn add_systems_once<K: Hash + Eq + Send + Sync + 'static, M>(
+        &mut self,
+        key: K,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> AddOutcome {
+        let event = match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => event,
+            Err(err) => {
+                reject(self, err);
+                return AddOutcome::Failed(err);
+            }
+        };
+        let new = self
+            .get_resource_or_insert_with::<SystemsAddedOnce>(Default::default)
+            .insert(key);
+        if !new {
+            return AddOutcome::Deduplicated;
+        }
+        send_world(self, event);
+        AddOutcome::Queued
+    }
+
+    fn try_add_systems_once<K: Hash + Eq + Send + Sync + 'static, M>(
+        &mut self,
+        key: K,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> AddOutcome {
+        let event = match AddSystems::try_new_in(self, schedule, systems) {
+            Ok(event) => event,
+            Err(err) => return AddOutcome::Failed(err),
+        };
+        if !self.contains_resource::<ConsumableEvents<AddSystems>>() {
+            return AddOutcome::Failed(AddSystemsError::MissingEventBuffer);
+        }
+        let Some(mut once) = self.get_resource_mut::<SystemsAddedOnce>() else {
+            return AddOutcome::Failed(AddSystemsError::MissingAddedOnce);
+        };
+        if !once.insert(key) {
+            return AddOutcome::Deduplicated;
+        }
+
+        let event = event.stamped(self).counted(self.get_resource_mut());
+        self.resource_mut::<ConsumableEvents<AddSystems>>().send(event);
+        AddOutcome::Queued
     }
 }
@@ -1,40 +1,1213 @@
 //! Logic for app
 
-use bevy_app::{App, Last, MainScheduleOrder, Plugin, SubApp};
-use bevy_consumable_event::ConsumableEventApp;
+use bevy_app::{
+    App, AppExit, First, FixedFirst, FixedLast, FixedPostUpdate, FixedPreUpdate, FixedUpdate, Last,
+    Main, MainScheduleOrder, Plugin, SubApp,
+};
+use bevy_ecs::{
+    event::Events,
+    schedule::{InternedScheduleLabel, IntoSystemConfigs, ScheduleLabel},
+    change_detection::DetectChangesMut,
+    system::{Local, ResMut, Resource, SystemState},
+    world::{DeferredWorld, FromWorld, World},
+};
+use bevy_utils::{tracing::warn, Duration};
+use std::{any::TypeId, hash::Hash};
 
 use crate::{
-    add_systems::{add_requested_systems, AddSystems, AddingSystems},
-    RegisterExtension, RegisteredTypes,
+    add_schedule::apply_pending_main_schedule_order,
+    add_systems::{
+        adding_systems_configs, apply_requested_systems, init_adding_systems_resources,
+        AddRequestedSystemsParams, AddingSystems, AddingSystemsLabel, SystemAdditionStats,
+    },
+    callbacks::{RegistrationCallbacks, RegistrationRecord},
+    hints::{hinted_registrations, LazyRegistrations},
+    registration_bundle::{RegisterValue, RegistrationBundle},
+    timings::RegistrationTimings,
+    init_plugin_registration_resources, RegisterExtension, RegisterInWorld, RegisterInWorldValued, RegisterScope, RegisterKeyedInWorld, RegisteredTypes,
 };
 
 /// Adds functionality to be able to register types into the world 
 /// and add system during runtime.
-pub struct RegisterInWorldPlugin;
+///
+/// Types can be registered before this plugin is built. Systems requested during such 
+/// registrations are kept and added during the first run of [`AddingSystems`].
+///
+/// Consists of [`RegistrationPlugin`] and [`RuntimeSystemsPlugin`], which can be added on their own,
+/// when only one of the two is needed.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::prelude::*;
+///
+/// #[derive(Resource, Default)]
+/// struct Ran(bool);
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(Update, |mut ran: ResMut<Ran>| ran.0 = true);
+///     }
+/// }
+///
+/// struct MyPlugin;
+///
+/// impl Plugin for MyPlugin {
+///     fn build(&self, app: &mut App) {
+///         app.init_resource::<Ran>();
+///         app.register::<Foo>();
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((MyPlugin, RegisterInWorldPlugin::default()));
+///
+/// app.update();
+/// app.update();
+/// assert!(app.world().resource::<Ran>().0);
+/// ```
+///
+/// Adding the plugin more than once is allowed, every addition after the first one
+/// only logs a warning and its options are ignored. The types it [pre-registers](RegisterInWorldPlugin::register)
+/// are registered during finish of the first one, after the types of the first one.
+///
+/// ```
+/// # use bevy_app::{prelude::*, MainScheduleOrder, PluginGroupBuilder};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{add_systems::AddingSystems, prelude::*};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct NeverBuilt;
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// struct MyPlugins;
+///
+/// impl PluginGroup for MyPlugins {
+///     fn build(self) -> PluginGroupBuilder {
+///         PluginGroupBuilder::start::<Self>().add(RegisterInWorldPlugin::default())
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((
+///     RegisterInWorldPlugin::default(),
+///     RegisterInWorldPlugin::default().adding_systems_schedule(NeverBuilt).register::<Foo>(),
+///     MyPlugins,
+/// ));
+/// app.finish();
+/// assert!(app.is_registered::<Foo>());
+/// assert!(!app.world().resource::<Schedules>().contains(NeverBuilt));
+///
+/// let order = app.world().resource::<MainScheduleOrder>();
+/// let adding_systems = AddingSystems.intern();
+/// assert_eq!(order.labels.iter().filter(|&&label| label == adding_systems).count(), 1);
+///
+/// // `AddingSystems` already ran during finish, to add the systems requested by `Foo`
+/// let schedules = app.world().resource::<Schedules>();
+/// let adding_systems = schedules.get(AddingSystems).unwrap().systems().unwrap();
+/// assert_eq!(
+///     adding_systems.filter(|(_, system)| system.name().ends_with("add_requested_systems")).count(),
+///     1,
+/// );
+/// ```
+/// 
+/// By default [`AddingSystems`] runs right after [`Last`], 
+/// use [`insert_after`](RegisterInWorldPlugin::insert_after) 
+/// and [`insert_before`](RegisterInWorldPlugin::insert_before) to change that.
+///
+/// If the app has no [`MainScheduleOrder`] (for example, it doesn't use bevy's main schedule),
+/// [`AddingSystems`] is still set up, but isn't placed anywhere 
+/// and [`AddingSystemsPlacement`] is not inserted. In that case, run [`AddingSystems`] manually.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{add_systems::AddingSystems, prelude::*};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct Tick;
+///
+/// #[derive(Resource, Default)]
+/// struct Ran(bool);
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(Tick, |mut ran: ResMut<Ran>| ran.0 = true);
+///     }
+/// }
+///
+/// let mut app = App::empty();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.init_resource::<Ran>();
+/// app.init_schedule(Tick);
+///
+/// app.register::<Foo>();
+/// app.world_mut().run_schedule(AddingSystems);
+/// app.world_mut().run_schedule(Tick);
+/// assert!(app.world().resource::<Ran>().0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RegisterInWorldPlugin {
+    registration: RegistrationPlugin,
+    runtime_systems: RuntimeSystemsPlugin,
+}
+
+impl RegisterInWorldPlugin {
+    /// Use the `schedule` instead of [`AddingSystems`] for adding the requested systems and observers.
+    /// It's set up and placed in the [`MainScheduleOrder`] the same way [`AddingSystems`] would be,
+    /// and [`AddingSystemsLabel`] is inserted, so systems can't be added to it with [`AddSystems`](crate::add_systems::AddSystems) events either.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{add_systems::{AddSystemsError, AddingSystems}, prelude::*};
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct ApplyRuntimeSystems;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Ran(bool);
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems(Update, |mut ran: ResMut<Ran>| ran.0 = true);
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default().adding_systems_schedule(ApplyRuntimeSystems));
+    /// app.init_resource::<Ran>();
+    ///
+    /// let schedules = app.world().resource::<Schedules>();
+    /// assert!(schedules.contains(ApplyRuntimeSystems));
+    /// assert!(!schedules.contains(AddingSystems));
+    ///
+    /// app.register::<Foo>();
+    /// app.update();
+    /// app.update();
+    /// assert!(app.world().resource::<Ran>().0);
+    ///
+    /// assert_eq!(
+    ///     app.world_mut().try_add_systems(ApplyRuntimeSystems, || {}),
+    ///     Err(AddSystemsError::AddingSystemsSchedule),
+    /// );
+    /// ```
+    pub fn adding_systems_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.runtime_systems = self.runtime_systems.adding_systems_schedule(schedule);
+        self
+    }
+
+    /// Run [`AddingSystems`] right after the `anchor` schedule.
+    ///
+    /// # Panics
+    /// During build, if `anchor` is not in the [`MainScheduleOrder`].
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, MainScheduleOrder};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{add_systems::AddingSystems, prelude::*};
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct NetworkSend;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems(NetworkSend, |mut log: ResMut<Log>| log.0.push("runtime system"));
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_resource::<Log>();
+    /// app.init_schedule(NetworkSend);
+    /// app.world_mut().resource_mut::<MainScheduleOrder>().insert_after(Last, NetworkSend);
+    /// app.add_plugins(RegisterInWorldPlugin::default().insert_before(NetworkSend));
+    /// app.add_systems(AddingSystems, |mut log: ResMut<Log>| log.0.push("adding systems"));
+    ///
+    /// app.register::<Foo>();
+    /// app.update();
+    ///
+    /// // Systems were added before `NetworkSend` ran, so the runtime system already ran this frame
+    /// assert_eq!(app.world().resource::<Log>().0, ["adding systems", "runtime system"]);
+    /// ```
+    pub fn insert_after(mut self, anchor: impl ScheduleLabel) -> Self {
+        self.runtime_systems = self.runtime_systems.insert_after(anchor);
+        self
+    }
+
+    /// Run [`AddingSystems`] right before the `anchor` schedule.
+    ///
+    /// # Panics
+    /// During build, if `anchor` is not in the [`MainScheduleOrder`].
+    pub fn insert_before(mut self, anchor: impl ScheduleLabel) -> Self {
+        self.runtime_systems = self.runtime_systems.insert_before(anchor);
+        self
+    }
+
+    /// Returns where [`AddingSystems`] will be placed in the [`MainScheduleOrder`].
+    pub fn placement(&self) -> AddingSystemsPlacement {
+        self.runtime_systems.placement()
+    }
+
+    /// Register the type during [`Plugin::finish`], after all the plugins were built. 
+    /// Types are registered in the order this method was called in, 
+    /// and [`AddingSystems`] is run right after, so the requested systems are there from the first frame.
+    ///
+    /// Deduplication is the same as with any other registration.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Count {
+    ///     registered: u32,
+    ///     ran: u32,
+    /// }
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Count>().registered += 1;
+    ///         world.add_systems(Update, |mut count: ResMut<Count>| count.ran += 1);
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_resource::<Count>();
+    /// app.add_plugins(RegisterInWorldPlugin::default().register::<Foo>());
+    /// app.finish();
+    /// app.cleanup();
+    ///
+    /// app.update();
+    /// assert_eq!(app.world().resource::<Count>().ran, 1);
+    ///
+    /// app.world_mut().spawn(Foo);
+    /// assert_eq!(app.world().resource::<Count>().registered, 1);
+    /// ```
+    pub fn register<T: RegisterInWorld>(mut self) -> Self {
+        self.registration = self.registration.register::<T>();
+        self
+    }
+
+    /// Measure every registration, recording it in [`RegistrationTimings`],
+    /// and log a warning naming the type when a registration takes longer than `threshold`.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::time::Duration;
+    /// use bevy_register_in_world::{prelude::*, timings::RegistrationTimings};
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Slow;
+    ///
+    /// impl RegisterInWorld for Slow {
+    ///     fn register(_world: DeferredWorld) {
+    ///         std::thread::sleep(Duration::from_millis(5));
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default().warn_if_register_exceeds(Duration::from_millis(1)));
+    ///
+    /// app.world_mut().spawn(Slow);
+    ///
+    /// let timings = app.world().resource::<RegistrationTimings>();
+    /// assert_eq!(timings.exceeded().count(), 1);
+    /// ```
+    pub fn warn_if_register_exceeds(mut self, threshold: Duration) -> Self {
+        self.registration = self.registration.warn_if_register_exceeds(threshold);
+        self
+    }
+
+    /// Add systems requested for [`FixedFirst`], [`FixedPreUpdate`], [`FixedUpdate`] and [`FixedPostUpdate`]
+    /// at the end of every fixed step, with [`add_requested_fixed_systems`] in [`FixedLast`].
+    /// Otherwise, systems requested during a fixed step are only added during [`AddingSystems`],
+    /// and miss the remaining fixed steps of the frame. Disabled by default.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, FixedMain};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{add_systems::SystemAdditionStats, prelude::*};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Steps(u32);
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Body;
+    ///
+    /// impl RegisterInWorld for Body {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems(FixedUpdate, |mut steps: ResMut<Steps>| steps.0 += 1);
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default().drain_between_fixed_steps(true));
+    /// app.init_resource::<Steps>();
+    /// app.add_systems(FixedUpdate, |mut commands: Commands, mut spawned: Local<bool>| {
+    ///     if !*spawned {
+    ///         commands.spawn(Body);
+    ///         *spawned = true;
+    ///     }
+    /// });
+    ///
+    /// // Three fixed steps in one frame
+    /// for _ in 0..3 {
+    ///     app.world_mut().run_schedule(FixedMain);
+    /// }
+    /// assert_eq!(app.world().resource::<Steps>().0, 2);
+    /// assert_eq!(app.world().resource::<SystemAdditionStats>().total, 1);
+    /// ```
+    pub fn drain_between_fixed_steps(mut self, enabled: bool) -> Self {
+        self.runtime_systems = self.runtime_systems.drain_between_fixed_steps(enabled);
+        self
+    }
+
+    /// [Shrink](RegisteredTypes::shrink_to_fit) [`RegisteredTypes`] during [`Last`] every `frames` frames.
+    /// Shrinking doesn't trigger change detection of the resource.
+    ///
+    /// # Panics
+    /// If `frames` is 0.
+    pub fn shrink_registered_types_every(mut self, frames: u32) -> Self {
+        self.registration = self.registration.shrink_registered_types_every(frames);
+        self
+    }
+
+    /// [Unregister](RegisterExtension::unregister_all) all the types during [`Last`] of the frame
+    /// an [`AppExit`] event was sent in, in reverse registration order.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct Connection;
+    ///
+    /// struct Network;
+    ///
+    /// impl RegisterInWorld for Network {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.commands().insert_resource(Connection);
+    ///     }
+    ///
+    ///     fn unregister(mut world: DeferredWorld) {
+    ///         world.commands().remove_resource::<Connection>();
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default().unregister_on_exit(true));
+    /// app.register::<Network>();
+    /// app.update();
+    /// assert!(app.world().contains_resource::<Connection>());
+    ///
+    /// app.world_mut().send_event(AppExit::Success);
+    /// app.update();
+    /// assert!(!app.world().contains_resource::<Connection>());
+    /// assert!(!app.is_registered::<Network>());
+    /// ```
+    pub fn unregister_on_exit(mut self, enabled: bool) -> Self {
+        self.registration = self.registration.unregister_on_exit(enabled);
+        self
+    }
+}
+
+/// Consumes [`AddSystems`](crate::add_systems::AddSystems) events for [`FixedFirst`], [`FixedPreUpdate`], [`FixedUpdate`]
+/// and [`FixedPostUpdate`], and adds the systems, leaving the other events for [`AddingSystems`].
+/// The systems are added and initialized the same way [`add_requested_systems`] does it.
+/// Added to [`FixedLast`] by [`RegisterInWorldPlugin::drain_between_fixed_steps`].
+///
+/// [`add_requested_systems`]: crate::add_systems::add_requested_systems
+pub fn add_requested_fixed_systems(world: &mut World, state: &mut SystemState<AddRequestedSystemsParams>) {
+    let fixed = [
+        FixedFirst.intern(),
+        FixedPreUpdate.intern(),
+        FixedUpdate.intern(),
+        FixedPostUpdate.intern(),
+    ];
+    apply_requested_systems(world, state, |event, _| fixed.contains(&event.schedule()));
+}
+
+/// Position of [`AddingSystems`] in the [`MainScheduleOrder`] relative to an anchor schedule.
+/// 
+/// Inserted as a resource by [`RegisterInWorldPlugin`] and [`install_into_sub_app`], so other code can reason about it.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddingSystemsPlacement {
+    /// Right after the schedule.
+    After(InternedScheduleLabel),
+    /// Right before the schedule.
+    Before(InternedScheduleLabel),
+}
+
+impl AddingSystemsPlacement {
+    /// The schedule [`AddingSystems`] is placed relative to.
+    pub fn anchor(&self) -> InternedScheduleLabel {
+        match self {
+            AddingSystemsPlacement::After(anchor) | AddingSystemsPlacement::Before(anchor) => *anchor,
+        }
+    }
+}
+
+impl Default for AddingSystemsPlacement {
+    fn default() -> Self {
+        AddingSystemsPlacement::After(Last.intern())
+    }
+}
 
 impl Plugin for RegisterInWorldPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<RegisteredTypes>();
+        if app.is_plugin_added::<Self>() {
+            warn!("`RegisterInWorldPlugin` was added more than once, ignoring the options of all additions except the first one");
+            self.registration.queue_pre_registrations(app);
+            return;
+        }
+
+        app.add_plugins((self.registration.clone(), self.runtime_systems.clone()));
+    }
 
-        // Adding systems
-        app.add_persistent_consumable_event::<AddSystems>();
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
 
-        app.init_schedule(AddingSystems);
+/// Types pre-registered by every added [`RegistrationPlugin`] and [`RegisterInWorldPlugin`],
+/// in the order they were added in.
+#[derive(Resource, Default)]
+struct PreRegistrations(Vec<fn(&mut World)>);
+
+/// Part of [`RegisterInWorldPlugin`] that sets up registration of types: [`RegisteredTypes`] and the resources
+/// that are updated during registrations, without adding systems during runtime.
+/// Registers the pre-registered and [hinted](crate::hints::RegisteredTypesHints) types during finish,
+/// and then runs the schedule in [`AddingSystemsLabel`], if it exists.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_consumable_event::ConsumableEvents;
+/// use bevy_register_in_world::{add_systems::{AddingSystems, AddSystems}, app::RegistrationPlugin, prelude::*, RegisteredTypes};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegistrationPlugin::default().register::<Foo>());
+/// assert!(app.world().contains_resource::<RegisteredTypes>());
+/// assert!(!app.world().contains_resource::<ConsumableEvents<AddSystems>>());
+/// assert!(!app.world().resource::<Schedules>().contains(AddingSystems));
+///
+/// app.finish();
+/// assert!(app.is_registered::<Foo>());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RegistrationPlugin {
+    pre_registered: Vec<fn(&mut World)>,
+    register_warn_threshold: Option<Duration>,
+    shrink_every: Option<u32>,
+    unregister_on_exit: bool,
+}
+
+impl RegistrationPlugin {
+    /// Register the type during [`Plugin::finish`], see [`RegisterInWorldPlugin::register`].
+    pub fn register<T: RegisterInWorld>(mut self) -> Self {
+        self.pre_registered.push(|world| world.register::<T>());
+        self
+    }
+
+    /// Measure every registration and warn about the ones longer than `threshold`,
+    /// see [`RegisterInWorldPlugin::warn_if_register_exceeds`].
+    pub fn warn_if_register_exceeds(mut self, threshold: Duration) -> Self {
+        self.register_warn_threshold = Some(threshold);
+        self
+    }
+
+    /// [Shrink](RegisteredTypes::shrink_to_fit) [`RegisteredTypes`] during [`Last`] every `frames` frames,
+    /// see [`RegisterInWorldPlugin::shrink_registered_types_every`].
+    ///
+    /// # Panics
+    /// If `frames` is 0.
+    pub fn shrink_registered_types_every(mut self, frames: u32) -> Self {
+        assert!(frames > 0, "Can't shrink `RegisteredTypes` every 0 frames");
+        self.shrink_every = Some(frames);
+        self
+    }
+
+    /// Unregister all the types when the app exits, see [`RegisterInWorldPlugin::unregister_on_exit`].
+    pub fn unregister_on_exit(mut self, enabled: bool) -> Self {
+        self.unregister_on_exit = enabled;
+        self
+    }
+
+    /// Adds the pre-registered types to the ones registered during finish of the first instance.
+    fn queue_pre_registrations(&self, app: &mut App) {
         app.world_mut()
-            .resource_mut::<MainScheduleOrder>()
-            .insert_after(Last, AddingSystems);
-        app.add_systems(AddingSystems, add_requested_systems);
+            .get_resource_or_insert_with(PreRegistrations::default)
+            .0
+            .extend(&self.pre_registered);
     }
 }
 
-impl RegisterExtension for App {
-    fn register<T: crate::RegisterInWorld>(&mut self) {
-        self.world_mut().register::<T>();
+impl Plugin for RegistrationPlugin {
+    fn build(&self, app: &mut App) {
+        self.queue_pre_registrations(app);
+        if app.is_plugin_added::<Self>() {
+            warn!("`RegistrationPlugin` was added more than once, ignoring the options of all additions except the first one");
+            return;
+        }
+
+        init_plugin_registration_resources(app.world_mut());
+        if let Some(threshold) = self.register_warn_threshold {
+            app.insert_resource(RegistrationTimings::new(threshold));
+        }
+
+        if let Some(every) = self.shrink_every {
+            app.add_systems(
+                Last,
+                move |mut registered: ResMut<RegisteredTypes>, mut frames: Local<u32>| {
+                    *frames += 1;
+                    if *frames >= every {
+                        *frames = 0;
+                        registered.bypass_change_detection().shrink_to_fit();
+                    }
+                },
+            );
+        }
+
+        if self.unregister_on_exit {
+            app.add_systems(Last, |world: &mut World, mut done: Local<bool>| {
+                let exiting = world
+                    .get_resource::<Events<AppExit>>()
+                    .is_some_and(|events| !events.is_empty());
+                if exiting && !*done {
+                    *done = true;
+                    world.unregister_all();
+                }
+            });
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        // The first instance to finish takes the types pre-registered by all of them
+        let Some(PreRegistrations(pre_registered)) = app.world_mut().remove_resource() else {
+            return;
+        };
+
+        let hinted = hinted_registrations(app.world());
+        if pre_registered.is_empty() && hinted.is_empty() {
+            return;
+        }
+
+        let world = app.world_mut();
+        for register in pre_registered.iter().chain(&hinted) {
+            register(world);
+        }
+        // Without `RuntimeSystemsPlugin`, the requested systems wait for `AddingSystems` to be run manually
+        let Some(&AddingSystemsLabel(adding_systems)) = world.get_resource::<AddingSystemsLabel>() else {
+            return;
+        };
+        if let Err(error) = world.try_run_schedule(adding_systems) {
+            warn!("{error} The systems requested by pre-registered types are added when it runs");
+        }
+    }
+
+    fn is_unique(&self) -> bool {
+        false
     }
 }
 
-impl RegisterExtension for SubApp {
-    fn register<T: crate::RegisterInWorld>(&mut self) {
-        self.world_mut().register::<T>();
+/// Part of [`RegisterInWorldPlugin`] that sets up adding systems and observers during runtime:
+/// [`AddingSystems`], the event buffers and the resources updated while adding systems,
+/// without [`RegisteredTypes`].
+///
+/// Types in [`RegistrationStage::Deferred`](crate::RegistrationStage::Deferred) are queued
+/// in [`DeferredRegistrations`](crate::deferred::DeferredRegistrations), which is initialized by this plugin, because it's applied during [`AddingSystems`].
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// use bevy_register_in_world::{
+///     add_systems::AddingSystems,
+///     app::{AddingSystemsPlacement, RuntimeSystemsPlugin},
+///     prelude::*,
+///     RegisteredTypes,
+/// };
+///
+/// #[derive(Resource, Default)]
+/// struct Ran(bool);
+///
+/// let mut app = App::new();
+/// app.add_plugins(RuntimeSystemsPlugin::default().insert_before(First));
+/// app.init_resource::<Ran>();
+/// assert!(!app.world().contains_resource::<RegisteredTypes>());
+/// assert!(app.world().resource::<Schedules>().contains(AddingSystems));
+/// assert_eq!(
+///     *app.world().resource::<AddingSystemsPlacement>(),
+///     AddingSystemsPlacement::Before(First.intern()),
+/// );
+///
+/// app.world_mut().add_systems(Update, |mut ran: ResMut<Ran>| ran.0 = true);
+/// app.update();
+/// app.update();
+/// assert!(app.world().resource::<Ran>().0);
+/// assert!(!app.world().contains_resource::<RegisteredTypes>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuntimeSystemsPlugin {
+    adding_systems: InternedScheduleLabel,
+    placement: AddingSystemsPlacement,
+    drain_between_fixed_steps: bool,
+}
+
+impl Default for RuntimeSystemsPlugin {
+    fn default() -> Self {
+        Self {
+            adding_systems: AddingSystems.intern(),
+            placement: AddingSystemsPlacement::default(),
+            drain_between_fixed_steps: false,
+        }
     }
 }
+
+impl RuntimeSystemsPlugin {
+    /// Use the `schedule` instead of [`AddingSystems`], see [`RegisterInWorldPlugin::adding_systems_schedule`].
+    pub fn adding_systems_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.adding_systems = schedule.intern();
+        self
+    }
+
+    /// Run [`AddingSystems`] right after the `anchor` schedule, see [`RegisterInWorldPlugin::insert_after`].
+    ///
+    /// # Panics
+    /// During build, if `anchor` is not in the [`MainScheduleOrder`].
+    pub fn insert_after(mut self, anchor: impl ScheduleLabel) -> Self {
+        self.placement = AddingSystemsPlacement::After(anchor.intern());
+        self
+    }
+
+    /// Run [`AddingSystems`] right before the `anchor` schedule.
+    ///
+    /// # Panics
+    /// During build, if `anchor` is not in the [`MainScheduleOrder`].
+    pub fn insert_before(mut self, anchor: impl ScheduleLabel) -> Self {
+        self.placement = AddingSystemsPlacement::Before(anchor.intern());
+        self
+    }
+
+    /// Returns where [`AddingSystems`] will be placed in the [`MainScheduleOrder`].
+    pub fn placement(&self) -> AddingSystemsPlacement {
+        self.placement
+    }
+
+    /// Add systems requested for the fixed schedules at the end of every fixed step,
+    /// see [`RegisterInWorldPlugin::drain_between_fixed_steps`].
+    pub fn drain_between_fixed_steps(mut self, enabled: bool) -> Self {
+        self.drain_between_fixed_steps = enabled;
+        self
+    }
+}
+
+impl Plugin for RuntimeSystemsPlugin {
+    fn build(&self, app: &mut App) {
+        if app.is_plugin_added::<Self>() {
+            warn!("`RuntimeSystemsPlugin` was added more than once, ignoring all additions except the first one");
+            return;
+        }
+
+        init_adding_systems_resources(app.world_mut());
+        app.add_systems(First, reset_frame_stats);
+
+        let adding_systems = self.adding_systems;
+        app.insert_resource(AddingSystemsLabel(adding_systems));
+        app.init_schedule(adding_systems);
+
+        app.add_systems(adding_systems, adding_systems_configs());
+        #[cfg(feature = "debug-add-systems")]
+        app.add_systems(
+            adding_systems,
+            crate::add_systems::log_added_systems
+                .after(crate::add_systems::RegisterInWorldSet::ApplyAddSystems),
+        );
+        if self.drain_between_fixed_steps {
+            app.add_systems(FixedLast, add_requested_fixed_systems);
+        }
+
+        let Some(mut order) = app.world_mut().get_resource_mut::<MainScheduleOrder>() else {
+            warn!("`MainScheduleOrder` doesn't exist, so `AddingSystems` won't run automatically. Run it manually to add requested systems");
+            return;
+        };
+
+        let anchor = self.placement.anchor();
+        assert!(
+            order.labels.contains(&anchor),
+            "Can't place `AddingSystems` relative to {anchor:?}: the schedule is not in the `MainScheduleOrder`",
+        );
+        place_in_main_schedule_order(&mut order, adding_systems, self.placement);
+        app.insert_resource(self.placement);
+        app.add_systems(Main, apply_pending_main_schedule_order.after(Main::run_main));
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// Resets [`SystemAdditionStats::frame`] at the start of the frame.
+fn reset_frame_stats(mut stats: ResMut<SystemAdditionStats>) {
+    stats.frame = 0;
+}
+
+/// Inserts `adding_systems` into the `order` according to the `placement`.
+fn place_in_main_schedule_order(
+    order: &mut MainScheduleOrder,
+    adding_systems: InternedScheduleLabel,
+    placement: AddingSystemsPlacement,
+) {
+    match placement {
+        AddingSystemsPlacement::After(anchor) => order.insert_after(anchor, adding_systems),
+        AddingSystemsPlacement::Before(anchor) => order.insert_before(anchor, adding_systems),
+    }
+}
+
+/// Schedule that becomes the update schedule of a sub-app after [`install_into_sub_app`],
+/// when [`AddingSystems`] is placed relative to the update schedule of the sub-app.
+/// It runs the original update schedule and [`AddingSystems`] in the order of the placement.
+#[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+pub struct SubAppUpdate;
+
+/// Sets up registration of types and adding systems during runtime in the `sub_app`,
+/// with the same resources [`RegisterInWorldPlugin`] initializes in the main app.
+///
+/// [`AddingSystems`] is placed according to the `placement`:
+/// - if the sub-app has a [`MainScheduleOrder`] containing the anchor, it's placed in the order,
+///   the same way [`RegisterInWorldPlugin`] does it;
+/// - if the anchor is the update schedule of the sub-app, the update schedule is replaced with [`SubAppUpdate`],
+///   which runs the original update schedule and [`AddingSystems`] in the order of the placement.
+///
+/// Call this after the update schedule of the sub-app is set.
+///
+/// Unlike [`RegisterInWorldPlugin`], this sets up [`AddingSystems`] only: there is no drain of the systems
+/// requested for the fixed schedules between fixed steps, and [`SystemAdditionStats::frame`] is never reset.
+/// When [`AddingSystems`] runs from [`SubAppUpdate`], the sub-app has no [`MainScheduleOrder`],
+/// so the placements of schedules requested with [`AddSchedule`](crate::add_schedule::AddSchedule) are never applied.
+///
+/// # Panics
+/// If the anchor is neither in the [`MainScheduleOrder`] of the sub-app nor its update schedule.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_app::SubApp;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{app::{install_into_sub_app, AddingSystemsPlacement}, prelude::*};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct Render;
+///
+/// #[derive(Resource, Default)]
+/// struct Ran(u32);
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(Render, |mut ran: ResMut<Ran>| ran.0 += 1);
+///     }
+/// }
+///
+/// let mut sub_app = SubApp::new();
+/// sub_app.init_resource::<Ran>();
+/// sub_app.update_schedule = Some(Render.intern());
+/// install_into_sub_app(&mut sub_app, AddingSystemsPlacement::After(Render.intern()));
+///
+/// sub_app.register::<Foo>();
+/// sub_app.update();
+/// sub_app.update();
+/// assert_eq!(sub_app.world().resource::<Ran>().0, 1);
+/// ```
+///
+/// Placed before the update schedule, the requested systems run in the same update,
+/// and the pending additions are tracked like in the main app:
+///
+/// ```
+/// # use bevy_app::SubApp;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// use bevy_register_in_world::{
+///     add_systems::{no_pending_system_additions, PendingSystemAdditions},
+///     app::{install_into_sub_app, AddingSystemsPlacement},
+///     prelude::*,
+/// };
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct Render;
+///
+/// #[derive(Resource, Default)]
+/// struct Ran(u32);
+///
+/// let mut sub_app = SubApp::new();
+/// sub_app.init_resource::<Ran>();
+/// sub_app.update_schedule = Some(Render.intern());
+/// install_into_sub_app(&mut sub_app, AddingSystemsPlacement::Before(Render.intern()));
+///
+/// sub_app.world_mut().add_systems(Render, |mut ran: ResMut<Ran>| ran.0 += 1);
+/// assert_eq!(sub_app.world().resource::<PendingSystemAdditions>().get(Render), 1);
+///
+/// sub_app.update();
+/// assert_eq!(sub_app.world().resource::<Ran>().0, 1);
+/// assert!(sub_app.world().resource::<PendingSystemAdditions>().is_empty());
+/// ```
+pub fn install_into_sub_app(sub_app: &mut SubApp, placement: AddingSystemsPlacement) {
+    if sub_app.world().contains_resource::<AddingSystemsLabel>() {
+        warn!("Already installed into the sub-app, ignoring");
+        return;
+    }
+
+    let world = sub_app.world_mut();
+    init_plugin_registration_resources(world);
+    init_adding_systems_resources(world);
+    world.insert_resource(AddingSystemsLabel(AddingSystems.intern()));
+    world.insert_resource(placement);
+    sub_app.init_schedule(AddingSystems);
+    sub_app.add_systems(AddingSystems, adding_systems_configs());
+
+    let anchor = placement.anchor();
+    if let Some(mut order) = sub_app
+        .world_mut()
+        .get_resource_mut::<MainScheduleOrder>()
+        .filter(|order| order.labels.contains(&anchor))
+    {
+        place_in_main_schedule_order(&mut order, AddingSystems.intern(), placement);
+        sub_app.add_systems(Main, apply_pending_main_schedule_order.after(Main::run_main));
+        return;
+    }
+
+    assert!(
+        sub_app.update_schedule == Some(anchor),
+        "Can't place `AddingSystems` relative to {anchor:?}: the schedule is neither in the `MainScheduleOrder` nor the update schedule of the sub-app",
+    );
+    sub_app.init_schedule(anchor);
+    sub_app.add_systems(SubAppUpdate, move |world: &mut World| match placement {
+        AddingSystemsPlacement::After(update_schedule) => {
+            world.run_schedule(update_schedule);
+            world.run_schedule(AddingSystems);
+        }
+        AddingSystemsPlacement::Before(update_schedule) => {
+            world.run_schedule(AddingSystems);
+            world.run_schedule(update_schedule);
+        }
+    });
+    sub_app.update_schedule = Some(SubAppUpdate.intern());
+}
+
+/// Extension trait for [`App`] to add systems to [`AddingSystems`] itself.
+pub trait AddingSystemsApp {
+    /// Adds the systems to [`AddingSystems`], or to the schedule in [`AddingSystemsLabel`] if it exists,
+    /// directly with [`App::add_systems`]. The systems are unordered relative to [`RegisterInWorldSet`],
+    /// order them explicitly if needed.
+    ///
+    /// [`AddSystems`](crate::add_systems::AddSystems) events can't target [`AddingSystems`], because the events are consumed
+    /// while it runs. Use this for the rare systems that belong to the system-adding phase,
+    /// like a drainer of another event.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_register_in_world::{app::AddingSystemsApp, prelude::*};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Ran(bool);
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<Ran>();
+    /// app.add_to_adding_systems(|mut ran: ResMut<Ran>| ran.0 = true);
+    ///
+    /// app.update();
+    /// assert!(app.world().resource::<Ran>().0);
+    /// ```
+    ///
+    /// [`RegisterInWorldSet`]: crate::add_systems::RegisterInWorldSet
+    fn add_to_adding_systems<M>(&mut self, systems: impl IntoSystemConfigs<M>) -> &mut Self;
+}
+
+impl AddingSystemsApp for App {
+    fn add_to_adding_systems<M>(&mut self, systems: impl IntoSystemConfigs<M>) -> &mut Self {
+        let label = self
+            .world()
+            .get_resource::<AddingSystemsLabel>()
+            .copied()
+            .unwrap_or_default();
+        self.add_systems(label.0, systems)
+    }
+}
+
+/// Extension trait for [`App`] to add [`RegistrationCallbacks`].
+pub trait RegistrationCallbacksApp {
+    /// Adds the callback that is called right after every registration of a type into the world.
+    /// Initializes [`RegistrationCallbacks`] if it doesn't exist.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::sync::{Arc, Mutex};
+    /// use bevy_register_in_world::{app::RegistrationCallbacksApp, prelude::*};
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// let registered = Arc::new(Mutex::new(0));
+    /// let registered_clone = registered.clone();
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.on_registration(move |_record| *registered_clone.lock().unwrap() += 1);
+    ///
+    /// app.world_mut().spawn(Foo);
+    /// app.world_mut().spawn(Foo);
+    /// assert_eq!(*registered.lock().unwrap(), 1);
+    /// ```
+    fn on_registration(
+        &mut self,
+        callback: impl Fn(&RegistrationRecord) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl RegistrationCallbacksApp for App {
+    fn on_registration(
+        &mut self,
+        callback: impl Fn(&RegistrationRecord) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with::<RegistrationCallbacks>(Default::default)
+            .push(callback);
+        self
+    }
+}
+
+/// Extension trait for [`App`] to record types that are registered lazily,
+/// unless hinted in [`RegisteredTypesHints`](crate::hints::RegisteredTypesHints).
+pub trait LazyRegistrationApp {
+    /// Records the register function of the type in [`LazyRegistrations`], without running it.
+    /// If the name of the type is in [`RegisteredTypesHints`](crate::hints::RegisteredTypesHints)
+    /// when [`RegistrationPlugin`] finishes, the type is registered eagerly,
+    /// the same as with [`RegisterInWorldPlugin::register`].
+    /// Otherwise, it's registered when it's first needed, as usual.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{app::LazyRegistrationApp, hints::RegisteredTypesHints, prelude::*};
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Hot;
+    ///
+    /// impl RegisterInWorld for Hot {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Cold;
+    ///
+    /// impl RegisterInWorld for Cold {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.insert_resource(RegisteredTypesHints::new([std::any::type_name::<Hot>()]));
+    /// app.register_lazy::<Hot>().register_lazy::<Cold>();
+    /// app.finish();
+    /// app.cleanup();
+    ///
+    /// assert!(app.is_registered::<Hot>());
+    /// assert!(!app.is_registered::<Cold>());
+    ///
+    /// app.world_mut().spawn(Cold);
+    /// assert!(app.is_registered::<Cold>());
+    /// ```
+    fn register_lazy<T: RegisterInWorld>(&mut self) -> &mut Self;
+
+    /// Registers the types with the `names` right away, using register functions from the `registry`.
+    /// Names that are not in the `registry` are skipped with a warning.
+    ///
+    /// Use it with names saved by [`RegisteredTypes::to_stable_names`], so the systems
+    /// of the types are already added when a save is loaded, instead of after the first frame.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{app::LazyRegistrationApp, hints::LazyRegistrations, prelude::*, RegisteredTypes};
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Enemy;
+    ///
+    /// impl RegisterInWorld for Enemy {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems(Update, || {});
+    ///     }
+    /// }
+    ///
+    /// fn build_app() -> App {
+    ///     let mut app = App::new();
+    ///     app.add_plugins(RegisterInWorldPlugin::default());
+    ///     app.register_lazy::<Enemy>();
+    ///     app
+    /// }
+    ///
+    /// // Previous session
+    /// let mut app = build_app();
+    /// app.world_mut().spawn(Enemy);
+    /// let saved = app.world().resource::<RegisteredTypes>().to_stable_names();
+    /// assert_eq!(saved, [std::any::type_name::<Enemy>()]);
+    ///
+    /// // Loading the save
+    /// let mut app = build_app();
+    /// let registry = app.world().resource::<LazyRegistrations>().clone();
+    /// app.preregister_by_names(saved.iter().chain([&"unknown::Type".to_string()]), &registry);
+    /// app.update();
+    ///
+    /// let schedules = app.world().resource::<Schedules>();
+    /// assert_eq!(schedules.get(Update).unwrap().systems_len(), 1);
+    /// ```
+    fn preregister_by_names<S: AsRef<str>>(
+        &mut self,
+        names: impl IntoIterator<Item = S>,
+        registry: &LazyRegistrations,
+    ) -> &mut Self;
+}
+
+impl LazyRegistrationApp for App {
+    fn register_lazy<T: RegisterInWorld>(&mut self) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with::<LazyRegistrations>(Default::default)
+            .insert::<T>();
+        self
+    }
+
+    fn preregister_by_names<S: AsRef<str>>(
+        &mut self,
+        names: impl IntoIterator<Item = S>,
+        registry: &LazyRegistrations,
+    ) -> &mut Self {
+        for name in names {
+            let name = name.as_ref();
+            match registry.get(name) {
+                Some(register) => register(self.world_mut()),
+                None => warn!("Can't preregister `{name}`, it has no recorded register function"),
+            }
+        }
+        self
+    }
+}
+
+impl RegisterScope for App {
+    fn register_scope<R>(&mut self, scope: impl FnOnce(&mut DeferredWorld) -> R) -> R {
+        self.world_mut().register_scope(scope)
+    }
+}
+
+impl RegisterValue for App {
+    fn register_value(&mut self, bundle: RegistrationBundle) {
+        self.world_mut().register_value(bundle);
+    }
+
+    fn register_named(&mut self, name: &str) {
+        self.world_mut().register_named(name);
+    }
+}
+
+macro_rules! register_extension_for_app {
+    ($($app:ident),*) => {
+        $(
+            impl RegisterExtension for $app {
+                fn register<T: RegisterInWorld>(&mut self) {
+                    self.world_mut().register::<T>();
+                }
+
+                fn register_with_label<T: RegisterInWorld>(&mut self, label: impl Hash + Eq + Send + Sync + 'static) {
+                    self.world_mut().register_with_label::<T>(label);
+                }
+
+                fn register_as<T: RegisterInWorld>(&mut self, as_id: TypeId) {
+                    self.world_mut().register_as::<T>(as_id);
+                }
+
+                fn register_keyed<T, K>(&mut self, key: K)
+                where
+                    T: RegisterKeyedInWorld<K>,
+                    K: Hash + Eq + Send + Sync + 'static,
+                {
+                    self.world_mut().register_keyed::<T, K>(key);
+                }
+
+                fn register_with_value<T: RegisterInWorldValued>(&mut self, config: T::Config) {
+                    self.world_mut().register_with_value::<T>(config);
+                }
+
+                fn register_from_world<T: RegisterInWorld + Resource + FromWorld>(&mut self) {
+                    self.world_mut().register_from_world::<T>();
+                }
+
+                fn is_registered<T: RegisterInWorld>(&self) -> bool {
+                    self.world().is_registered::<T>()
+                }
+
+                fn registered_type_names(&self) -> Vec<&'static str> {
+                    self.world().registered_type_names()
+                }
+
+                fn unregister_all(&mut self) {
+                    self.world_mut().unregister_all();
+                }
+            }
+        )*
+    };
+}
+
+register_extension_for_app!(App, SubApp);
@@ -0,0 +1,127 @@
+//! One-shot systems that are registered once and found by a marker type
+
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    system::{Commands, IntoSystem, Resource, SystemId},
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::warn;
+
+/// Stores the [`SystemId`] of a one-shot system registered by
+/// [`register_cached_system`](RegisterCachedSystem::register_cached_system) for the `Marker`.
+///
+/// The convention is to use a marker type with the same generic parameters as the registered type,
+/// like `CachedSystemId<MyMarker<A, B>>`, so every generic instantiation has its own system.
+pub struct CachedSystemId<Marker> {
+    id: SystemId,
+    marker: PhantomData<fn() -> Marker>,
+}
+
+impl<Marker: 'static> Resource for CachedSystemId<Marker> {}
+
+impl<Marker> CachedSystemId<Marker> {
+    /// The id of the cached system.
+    #[inline]
+    pub fn id(&self) -> SystemId {
+        self.id
+    }
+}
+
+/// Registers one-shot systems and caches their ids in [`CachedSystemId`] resources.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::marker::PhantomData;
+/// use bevy_register_in_world::{
+///     cached_system::{run_cached, CachedSystemId, RegisterCachedSystem},
+///     prelude::*,
+/// };
+///
+/// #[derive(Resource, Default)]
+/// struct Log(Vec<&'static str>);
+///
+/// struct Spawner<T>(PhantomData<T>);
+///
+/// struct SpawnerMarker<T>(PhantomData<T>);
+///
+/// impl<T: Send + Sync + 'static> RegisterInWorld for Spawner<T> {
+///     fn register(mut world: DeferredWorld) {
+///         world.register_cached_system::<SpawnerMarker<T>, _>(|mut log: ResMut<Log>| {
+///             log.0.push(std::any::type_name::<T>())
+///         });
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Log>();
+/// world.register::<Spawner<u8>>();
+/// world.register::<Spawner<u16>>();
+///
+/// let u8_id = world.resource::<CachedSystemId<SpawnerMarker<u8>>>().id();
+/// let u16_id = world.resource::<CachedSystemId<SpawnerMarker<u16>>>().id();
+/// assert_ne!(u8_id, u16_id);
+///
+/// run_cached::<SpawnerMarker<u16>>(&mut world.commands());
+/// run_cached::<SpawnerMarker<u8>>(&mut world.commands());
+/// world.flush();
+/// assert_eq!(world.resource::<Log>().0, ["u16", "u8"]);
+/// ```
+pub trait RegisterCachedSystem {
+    /// Registers the one-shot `system` and inserts [`CachedSystemId<Marker>`] with its id.
+    /// Does nothing if [`CachedSystemId<Marker>`] already exists.
+    fn register_cached_system<Marker: 'static, M>(
+        &mut self,
+        system: impl IntoSystem<(), (), M> + Send + 'static,
+    );
+}
+
+impl RegisterCachedSystem for World {
+    fn register_cached_system<Marker: 'static, M>(
+        &mut self,
+        system: impl IntoSystem<(), (), M> + Send + 'static,
+    ) {
+        if self.contains_resource::<CachedSystemId<Marker>>() {
+            return;
+        }
+
+        let id = self.register_system(system);
+        self.insert_resource(CachedSystemId::<Marker> {
+            id,
+            marker: PhantomData,
+        });
+    }
+}
+
+impl RegisterCachedSystem for DeferredWorld<'_> {
+    /// Queues the registration through commands.
+    fn register_cached_system<Marker: 'static, M>(
+        &mut self,
+        system: impl IntoSystem<(), (), M> + Send + 'static,
+    ) {
+        self.commands().add(move |world: &mut World| {
+            world.register_cached_system::<Marker, M>(system);
+        });
+    }
+}
+
+/// Queues running the system cached in [`CachedSystemId<Marker>`].
+/// Logs a warning if the resource doesn't exist when the command is applied.
+pub fn run_cached<Marker: 'static>(commands: &mut Commands) {
+    commands.add(|world: &mut World| {
+        let Some(id) = world
+            .get_resource::<CachedSystemId<Marker>>()
+            .map(CachedSystemId::id)
+        else {
+            warn!(
+                "Can't run cached system: `CachedSystemId<{}>` doesn't exist",
+                std::any::type_name::<Marker>()
+            );
+            return;
+        };
+        if let Err(err) = world.run_system(id) {
+            warn!("Can't run cached system: {err}");
+        }
+    });
+}
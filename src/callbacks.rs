@@ -0,0 +1,78 @@
+//! Callbacks on registrations
+
+use std::any::TypeId;
+
+use bevy_ecs::{component::Tick, system::Resource};
+
+use crate::RegistrationOrigin;
+
+/// Information about a registration, passed to [`RegistrationCallbacks`].
+#[derive(Debug, Clone)]
+pub struct RegistrationRecord {
+    /// [`TypeId`] of the registered type.
+    pub type_id: TypeId,
+    /// Name of the registered type.
+    pub type_name: &'static str,
+    /// Change tick of the world when the registration happened.
+    pub tick: Tick,
+    /// What caused the registration.
+    pub origin: RegistrationOrigin,
+}
+
+type Callback = Box<dyn Fn(&RegistrationRecord) + Send + Sync>;
+
+/// Callbacks that are called right after every registration of a type into the world,
+/// in the order they were added in.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::sync::{Arc, Mutex};
+/// use bevy_register_in_world::{callbacks::RegistrationCallbacks, prelude::*};
+///
+/// struct Foo;
+/// struct Bar;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// impl RegisterInWorld for Bar {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let seen = Arc::new(Mutex::new(Vec::new()));
+/// let mut callbacks = RegistrationCallbacks::default();
+/// let seen_clone = seen.clone();
+/// callbacks.push(move |record| seen_clone.lock().unwrap().push(record.type_name));
+///
+/// let mut world = World::new();
+/// world.insert_resource(callbacks);
+///
+/// world.register::<Foo>();
+/// world.register::<Bar>();
+/// world.register::<Foo>();
+///
+/// assert_eq!(
+///     *seen.lock().unwrap(),
+///     [std::any::type_name::<Foo>(), std::any::type_name::<Bar>()],
+/// );
+/// ```
+#[derive(Resource, Default)]
+pub struct RegistrationCallbacks {
+    callbacks: Vec<Callback>,
+}
+
+impl RegistrationCallbacks {
+    /// Adds the callback.
+    pub fn push(&mut self, callback: impl Fn(&RegistrationRecord) + Send + Sync + 'static) {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    /// Calls every callback with the `record`.
+    pub fn call(&self, record: &RegistrationRecord) {
+        for callback in &self.callbacks {
+            callback(record);
+        }
+    }
+}
@@ -1,10 +1,31 @@
 //! Component stuff
 
 use bevy_ecs::{
-    component::Component,
-    world::DeferredWorld,
+    component::{Component, ComponentId},
+    entity::Entity,
+    event::Event,
+    observer::Trigger,
+    world::{DeferredWorld, World},
 };
-use crate::{RegisterExtension, RegisterInWorld};
+#[cfg(any(feature = "hit-counts", feature = "registration-stats"))]
+use bevy_ecs::system::Resource;
+#[cfg(feature = "registration-stats")]
+use bevy_utils::HashMap;
+#[cfg(feature = "hit-counts")]
+use std::any::TypeId;
+use bevy_utils::tracing::error;
+use std::{
+    any::type_name,
+    marker::PhantomData,
+    mem::size_of,
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+};
+use crate::{
+    deferred::queue_deferred, mark_registered, run_registration, RegisterExtension, RegisterInWorld, RegisteredTypes,
+    RegistrationOrigin,
+};
+#[cfg(feature = "hit-counts")]
+use crate::TypeIdMap;
 
 pub use bevy_register_in_world_macros::ComponentAutoRegister;
 
@@ -13,14 +34,758 @@ pub use bevy_register_in_world_macros::ComponentAutoRegister;
 /// You can derive this trait and use the same attributes as the regular [`Component`] derive.
 /// In other words, you can still specify storage type and different hooks. 
 /// `on_add` hook will be called after the registration.
+///
+/// Hooks can be associated functions of the component, written as `Self::function`.
+/// They are instantiated with the generic parameters of the component.
+/// Paths to hooks can also be written as string literals.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::component::ComponentId;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(Resource, Default)]
+/// struct Added(Vec<&'static str>);
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(on_add = Self::on_add_hook, on_remove = "Self::on_remove_hook")]
+/// struct Generic<T: Send + Sync + 'static>(T);
+///
+/// impl<T: Send + Sync + 'static> Generic<T> {
+///     fn on_add_hook(mut world: DeferredWorld, _entity: Entity, _id: ComponentId) {
+///         world.resource_mut::<Added>().0.push(std::any::type_name::<T>());
+///     }
+///
+///     fn on_remove_hook(mut world: DeferredWorld, _entity: Entity, _id: ComponentId) {
+///         world.resource_mut::<Added>().0.retain(|name| *name != std::any::type_name::<T>());
+///     }
+/// }
+///
+/// impl<T: Send + Sync + 'static> RegisterInWorld for Generic<T> {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Added>();
+/// world.init_resource::<RegisteredTypes>();
+///
+/// world.spawn(Generic(0u8));
+/// let entity = world.spawn(Generic(0u16)).id();
+/// assert_eq!(world.resource::<Added>().0, ["u8", "u16"]);
+///
+/// world.despawn(entity);
+/// assert_eq!(world.resource::<Added>().0, ["u8"]);
+/// ```
+///
+/// Const generic parameters are supported the same way, and every instantiation is registered separately.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(Resource, Default)]
+/// struct Sizes(Vec<(usize, usize)>);
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(storage = "SparseSet")]
+/// struct Grid<const W: usize, const H: usize>([[u8; W]; H]);
+///
+/// impl<const W: usize, const H: usize> RegisterInWorld for Grid<W, H> {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Sizes>().0.push((W, H));
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Sizes>();
+/// world.init_resource::<RegisteredTypes>();
+///
+/// world.spawn(Grid::<2, 3>([[0; 2]; 3]));
+/// world.spawn(Grid::<4, 4>([[0; 4]; 4]));
+/// world.spawn(Grid::<2, 3>([[1; 2]; 3]));
+///
+/// assert_eq!(world.resource::<Sizes>().0, [(2, 3), (4, 4)]);
+/// assert!(world.is_registered::<Grid<2, 3>>());
+/// assert!(world.is_registered::<Grid<4, 4>>());
+/// assert!(!world.is_registered::<Grid<3, 2>>());
+/// ```
+///
+/// Enums are supported the same way as structs, including generic parameters used only in some variants,
+/// `SparseSet` storage and the user's hooks.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::component::ComponentId;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(Resource, Default)]
+/// struct Log(Vec<String>);
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(storage = "SparseSet", on_add = Self::on_add_hook)]
+/// enum Ability<T: Send + Sync + 'static> {
+///     Ready(T),
+///     Cooldown { left: f32 },
+/// }
+///
+/// impl<T: Send + Sync + 'static> Ability<T> {
+///     fn on_add_hook(mut world: DeferredWorld, _entity: Entity, _id: ComponentId) {
+///         let registered = world.resource::<RegisteredTypes>().is_registered::<Self>();
+///         world.resource_mut::<Log>().0.push(format!("hook {registered}"));
+///     }
+/// }
+///
+/// impl<T: Send + Sync + 'static> RegisterInWorld for Ability<T> {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Log>().0.push(format!("register {}", std::any::type_name::<T>()));
+///     }
+/// }
+///
+/// #[derive(ComponentAutoRegister, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Phase {
+///     Menu,
+///     Playing,
+/// }
+///
+/// impl RegisterInWorld for Phase {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Log>().0.push("register phase".into());
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Log>();
+/// world.init_resource::<RegisteredTypes>();
+///
+/// world.spawn(Ability::Ready(1u8));
+/// world.spawn(Ability::<u8>::Cooldown { left: 1.0 });
+/// world.spawn((Phase::Menu, Ability::<u16>::Cooldown { left: 0.5 }));
+/// let entity = world.spawn(Phase::Playing).id();
+///
+/// assert_eq!(
+///     world.resource::<Log>().0,
+///     ["register u8", "hook true", "hook true", "register phase", "register u16", "hook true"],
+/// );
+/// assert_eq!(world.entity(entity).get::<Phase>(), Some(&Phase::Playing));
+/// ```
+///
+/// Deriving for enums without variants is rejected, since the component could never be added.
+///
+/// ```compile_fail
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::prelude::*;
+///
+/// #[derive(ComponentAutoRegister)]
+/// enum Never {}
+///
+/// impl RegisterInWorld for Never {
+///     fn register(_world: DeferredWorld) {}
+/// }
+/// ```
+///
+/// Components spawned from scenes are registered too, with no extra setup.
+/// The scene spawner inserts deserialized components through [`ReflectComponent`](bevy_ecs::reflect::ReflectComponent),
+/// which runs the hooks the same way as any other insertion. The component only needs to be reflected,
+/// and registered in the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry), as scenes require anyway.
+///
+/// No extra attribute is needed for that: `#[reflect(Component)]` of bevy's `Reflect` derive builds
+/// [`ReflectComponent`](bevy_ecs::reflect::ReflectComponent) on top of the derived [`Component`] implementation,
+/// so both [`insert`](bevy_ecs::reflect::ReflectComponent::insert)
+/// and [`apply_or_insert`](bevy_ecs::reflect::ReflectComponent::apply_or_insert) go through the hooks.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_reflect::Reflect;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(ComponentAutoRegister, Reflect)]
+/// #[reflect(Component)]
+/// struct Spawner {
+///     rate: f32,
+/// }
+///
+/// impl RegisterInWorld for Spawner {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// #[derive(ComponentAutoRegister, Reflect)]
+/// #[reflect(Component)]
+/// #[component(storage = "SparseSet")]
+/// struct Health(u32);
+///
+/// impl RegisterInWorld for Health {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// world.init_resource::<AppTypeRegistry>();
+/// world.resource::<AppTypeRegistry>().write().register::<Spawner>();
+/// world.resource::<AppTypeRegistry>().write().register::<Health>();
+///
+/// // What `DynamicScene::write_to_world` does for every deserialized component
+/// let registry = world.resource::<AppTypeRegistry>().clone();
+/// let registry = registry.read();
+/// let component = Spawner { rate: 2.0 }.clone_value();
+/// let reflect_component = registry
+///     .get_type_data::<ReflectComponent>(std::any::TypeId::of::<Spawner>())
+///     .unwrap();
+/// let mut entity = world.spawn_empty();
+/// reflect_component.apply_or_insert(&mut entity, &*component, &registry);
+///
+/// let reflect_component = registry
+///     .get_type_data::<ReflectComponent>(std::any::TypeId::of::<Health>())
+///     .unwrap();
+/// reflect_component.insert(&mut world.spawn_empty(), &Health(10), &registry);
+///
+/// assert!(world.resource::<RegisteredTypes>().is_registered::<Spawner>());
+/// assert!(world.resource::<RegisteredTypes>().is_registered::<Health>());
+/// ```
+///
+/// If a hook panics, an error naming the component, the hook and the panic message is logged,
+/// and the panic continues with its original payload.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::component::ComponentId;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use bevy_utils::tracing::Level;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use bevy_register_in_world::{prelude::*, test_utils::capture_logs, RegisteredTypes};
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(on_insert = faulty_hook)]
+/// struct Faulty;
+///
+/// impl RegisterInWorld for Faulty {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// fn faulty_hook(_world: DeferredWorld, _entity: Entity, _id: ComponentId) {
+///     panic!("oops");
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+///
+/// let mut payload = None;
+/// let logs = capture_logs(Level::ERROR, || {
+///     payload = catch_unwind(AssertUnwindSafe(|| {
+///         world.spawn(Faulty);
+///     }))
+///     .err();
+/// });
+/// assert_eq!(logs, [format!("`on_insert` hook of `{}` panicked: oops", std::any::type_name::<Faulty>())]);
+/// assert_eq!(payload.unwrap().downcast_ref::<&str>(), Some(&"oops"));
+/// ```
+///
+/// `#[component(register_only)]` is for zero-sized markers that only exist to trigger registration.
+/// Such components always use `SparseSet` storage, and after registration and the user's `on_add` hook,
+/// removal of the marker from the entity is queued, so it doesn't stay in archetypes.
+/// Add `keep = true` to keep the marker on the entity.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, test_utils::*};
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(register_only)]
+/// struct Marker;
+///
+/// impl RegisterInWorld for Marker {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(register_only, keep = true)]
+/// struct KeptMarker;
+///
+/// impl RegisterInWorld for KeptMarker {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut app = registration_test_app();
+/// let entity = app.world_mut().spawn((Marker, KeptMarker)).id();
+/// app.update();
+///
+/// assert_registered::<Marker>(&app);
+/// assert!(!app.world().entity(entity).contains::<Marker>());
+/// assert!(app.world().entity(entity).contains::<KeptMarker>());
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::prelude::*;
+///
+/// // Not zero-sized
+/// #[derive(ComponentAutoRegister)]
+/// #[component(register_only)]
+/// struct Marker(u32);
+///
+/// impl RegisterInWorld for Marker {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// World::new().spawn(Marker(0));
+/// ```
+///
+/// `#[component(require_preregistered)]` is for hot components that must not be registered lazily.
+/// Adding such a component never registers it, so it has to be registered manually, for example at startup.
+/// In debug builds, adding it before it's registered panics. In release builds the check is compiled out.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::prelude::*;
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(require_preregistered)]
+/// struct Hot;
+///
+/// impl RegisterInWorld for Hot {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.register::<Hot>();
+/// world.spawn(Hot);
+/// ```
+///
+/// ```should_panic
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(require_preregistered)]
+/// struct Hot;
+///
+/// impl RegisterInWorld for Hot {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// // Panics in debug builds, telling to register `Hot` at startup
+/// world.spawn(Hot);
+/// ```
+///
+/// Bare `#[component(on_add)]` states that registration is the only thing done when the component is added.
+/// `#[component(no_auto_register)]` skips the registration on add entirely, for components that are registered manually.
+/// The user's `on_add` hook still runs.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::component::ComponentId;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(on_add)]
+/// struct Explicit;
+///
+/// impl RegisterInWorld for Explicit {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(no_auto_register)]
+/// struct Manual;
+///
+/// impl RegisterInWorld for Manual {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// #[derive(Resource, Default)]
+/// struct Added(u32);
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(no_auto_register, on_add = count_added)]
+/// struct Counted;
+///
+/// impl RegisterInWorld for Counted {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// fn count_added(mut world: DeferredWorld, _entity: Entity, _id: ComponentId) {
+///     world.resource_mut::<Added>().0 += 1;
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// world.init_resource::<Added>();
+///
+/// world.spawn((Explicit, Manual, Counted));
+/// assert!(world.is_registered::<Explicit>());
+/// assert!(!world.is_registered::<Manual>());
+/// assert!(!world.is_registered::<Counted>());
+/// assert_eq!(world.resource::<Added>().0, 1);
+///
+/// world.register::<Manual>();
+/// assert!(world.is_registered::<Manual>());
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::prelude::*;
+///
+/// // Registration is the only purpose of `register_only` markers
+/// #[derive(ComponentAutoRegister)]
+/// #[component(register_only, no_auto_register)]
+/// struct Marker;
+///
+/// impl RegisterInWorld for Marker {
+///     fn register(_world: DeferredWorld) {}
+/// }
+/// ```
+///
+/// Relationship components need `bevy` 0.16, so `relationship` and `relationship_target` attributes are rejected.
+///
+/// ```compile_fail
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::prelude::*;
+///
+/// #[derive(ComponentAutoRegister)]
+/// #[component(relationship = "Children")]
+/// struct ChildOf(Entity);
+///
+/// impl RegisterInWorld for ChildOf {
+///     fn register(_world: DeferredWorld) {}
+/// }
+/// ```
 pub trait ComponentAutoRegister: Component + RegisterInWorld {}
 
 /// Should be called during [`on_add`] hook for every component that should be 
 /// automatically registered to the world when added.
+/// 
+/// Registers the type using [`RegisterInWorld::register_with_context`].
+/// It's safe to call outside of the hook with any [`DeferredWorld`]: registration is deduplicated
+/// the same as with [`RegisterExtension::register`], and if the `entity`
+/// doesn't exist, the type is registered with [`RegisterInWorld::register`], the same as manually.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::RunSystemOnce;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{component::register_on_add, prelude::*, RegisteredTypes, RegistrationOrigin};
+///
+/// #[derive(Resource, Default)]
+/// struct Registrations(u32);
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Registrations>().0 += 1;
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// world.init_resource::<Registrations>();
+/// let id = world.init_component::<Foo>();
+/// let despawned = world.spawn_empty().id();
+/// world.despawn(despawned);
+///
+/// // From a system, for an entity that doesn't exist anymore
+/// world.run_system_once(move |world: DeferredWorld| register_on_add::<Foo>(world, despawned, id));
+/// world.run_system_once(move |world: DeferredWorld| register_on_add::<Foo>(world, despawned, id));
+/// world.spawn(Foo);
+///
+/// assert_eq!(world.resource::<Registrations>().0, 1);
+/// assert_eq!(world.resource::<RegisteredTypes>().origin::<Foo>(), Some(RegistrationOrigin::Manual));
+/// ```
+/// 
+/// [`on_add`]: bevy_ecs::component::ComponentHooks::on_add
 pub fn register_on_add<T: ComponentAutoRegister>(
     mut world: DeferredWorld,
+    entity: Entity,
+    id: ComponentId,
+) {
+    #[cfg(feature = "hit-counts")]
+    if let Some(mut counts) = world.get_resource_mut::<RegistrationHitCounts>() {
+        counts.hit::<T>();
+    }
+
+    #[cfg(feature = "registration-stats")]
+    let was_registered = world
+        .get_resource::<RegisteredTypes>()
+        .is_some_and(RegisteredTypes::is_registered::<T>);
+
+    register_for_entity::<T>(&mut world, entity, id);
+
+    #[cfg(feature = "registration-stats")]
+    if let Some(mut stats) = world.get_resource_mut::<RegistrationStats>() {
+        stats.record::<T>(id, !was_registered);
+    }
+}
+
+/// Same as [`register_on_add`], for [`on_insert`] hook, so the type is registered
+/// when the component is inserted, even if it replaces the existing value.
+/// Doesn't count `RegistrationHitCounts`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::component::{ComponentHooks, StorageType};
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{
+///     component::{register_on_insert, ComponentAutoRegister},
+///     prelude::*,
+///     RegisteredTypes,
+/// };
+///
+/// struct Foo;
+///
+/// impl Component for Foo {
+///     const STORAGE_TYPE: StorageType = StorageType::Table;
+///
+///     fn register_component_hooks(hooks: &mut ComponentHooks) {
+///         hooks.on_insert(register_on_insert::<Self>);
+///     }
+/// }
+///
+/// impl ComponentAutoRegister for Foo {}
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+///
+/// let entity = world.spawn_empty().id();
+/// assert!(!world.is_registered::<Foo>());
+///
+/// world.entity_mut(entity).insert(Foo);
+/// assert!(world.is_registered::<Foo>());
+///
+/// // Replacing the value doesn't register it again
+/// world.entity_mut(entity).insert(Foo);
+/// assert_eq!(world.resource::<RegisteredTypes>().len(), 1);
+/// ```
+///
+/// [`on_insert`]: bevy_ecs::component::ComponentHooks::on_insert
+pub fn register_on_insert<T: ComponentAutoRegister>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    id: ComponentId,
+) {
+    register_for_entity::<T>(&mut world, entity, id);
+}
+
+/// Registers `T` from an observer of the component `C`, with the entity of the `trigger` as context,
+/// the same as [`register_on_add`]. Can be used as an observer directly,
+/// for types that are registered when another component is added, inserted or removed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{component::register_from_observer, prelude::*, RegisteredTypes};
+///
+/// #[derive(Component)]
+/// struct Sail;
+///
+/// struct Ship;
+///
+/// impl RegisterInWorld for Ship {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// world.observe(register_from_observer::<Ship, OnAdd, Sail>);
+///
+/// world.spawn_empty();
+/// world.flush();
+/// assert!(!world.is_registered::<Ship>());
+///
+/// world.spawn(Sail);
+/// world.flush();
+/// assert!(world.is_registered::<Ship>());
+/// ```
+pub fn register_from_observer<T: RegisterInWorld, E: Event, C: Component>(
+    trigger: Trigger<E, C>,
+    mut world: DeferredWorld,
 ) {
-    world.register::<T>();
+    match world.components().component_id::<C>() {
+        Some(id) => register_for_entity::<T>(&mut world, trigger.entity(), id),
+        None => world.register::<T>(),
+    }
+}
+
+/// Registers `T` with the context of the `entity`, or manually if the entity doesn't exist.
+fn register_for_entity<T: RegisterInWorld>(world: &mut DeferredWorld, entity: Entity, id: ComponentId) {
+    if world.get_entity(entity).is_none() {
+        world.register::<T>();
+        return;
+    }
+
+    if mark_registered::<T>(world) {
+        let origin = RegistrationOrigin::Component {
+            entity,
+            component: id,
+            name: entity_name(world, entity),
+        };
+        if queue_deferred::<T>(world, origin.clone()) {
+            return;
+        }
+        run_registration::<T>(world.reborrow(), origin, |world| {
+            T::register_with_context(world, entity, id)
+        });
+    }
+}
+
+/// The `Name` of the `entity`, recorded in [`RegistrationOrigin::Component`].
+#[cfg(feature = "bevy_core")]
+fn entity_name(world: &DeferredWorld, entity: Entity) -> Option<String> {
+    world.get::<bevy_core::Name>(entity).map(|name| name.as_str().to_owned())
+}
+
+#[cfg(not(feature = "bevy_core"))]
+fn entity_name(_world: &DeferredWorld, _entity: Entity) -> Option<String> {
+    None
+}
+
+/// Counts of how many times each [`ComponentAutoRegister`] component was added,
+/// including the additions after the first one, that don't register the type.
+/// Updated by [`register_on_add`] if the resource exists.
+/// Initialized by [`RegisterInWorldPlugin`](crate::app::RegisterInWorldPlugin).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{component::RegistrationHitCounts, prelude::*, RegisteredTypes};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// world.init_resource::<RegistrationHitCounts>();
+///
+/// for _ in 0..5 {
+///     world.spawn(Foo);
+/// }
+/// assert_eq!(world.resource::<RegistrationHitCounts>().hits_for::<Foo>(), 5);
+/// ```
+#[cfg(feature = "hit-counts")]
+#[derive(Resource, Default, Debug)]
+pub struct RegistrationHitCounts {
+    counts: TypeIdMap<u64>,
+}
+
+#[cfg(feature = "hit-counts")]
+impl RegistrationHitCounts {
+    /// How many times the component was added.
+    #[inline]
+    pub fn hits_for<T: ComponentAutoRegister>(&self) -> u64 {
+        self.counts.get(&TypeId::of::<T>()).copied().unwrap_or(0)
+    }
+
+    /// Iterates over [`TypeId`]s of the components and how many times they were added, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (TypeId, u64)> + '_ {
+        self.counts.iter().map(|(&id, &count)| (id, count))
+    }
+
+    fn hit<T: ComponentAutoRegister>(&mut self) {
+        *self.counts.entry(TypeId::of::<T>()).or_default() += 1;
+    }
+}
+
+/// Counts of how many times [`register_on_add`] was called for each component, and how many of those calls
+/// registered the type. Attempts that didn't register anything are the lookups paid by every entity
+/// after the first one. Updated by [`register_on_add`] if the resource exists.
+/// Initialized by [`RegisterInWorldPlugin`](crate::app::RegisterInWorldPlugin).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{component::RegistrationStats, debug::dump, prelude::*, RegisteredTypes};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// world.init_resource::<RegistrationStats>();
+///
+/// for _ in 0..5 {
+///     world.spawn(Foo);
+/// }
+/// let id = world.component_id::<Foo>().unwrap();
+/// let stats = world.resource::<RegistrationStats>().stats(id);
+/// assert_eq!(stats.attempts, 5);
+/// assert_eq!(stats.registrations, 1);
+/// assert_eq!(stats.redundant(), 4);
+/// let line = format!("  {}: 5 attempts, 1 registrations\n", std::any::type_name::<Foo>());
+/// assert!(dump(&world).ends_with(&format!("Registration stats:\n{line}")));
+/// ```
+#[cfg(feature = "registration-stats")]
+#[derive(Resource, Default, Debug)]
+pub struct RegistrationStats {
+    components: HashMap<ComponentId, ComponentRegistrationStats>,
+}
+
+/// Counts of a single component in [`RegistrationStats`].
+#[cfg(feature = "registration-stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComponentRegistrationStats {
+    /// Name of the component type.
+    pub name: &'static str,
+    /// How many times the component was added.
+    pub attempts: u64,
+    /// How many of the additions registered the type.
+    pub registrations: u64,
+}
+
+#[cfg(feature = "registration-stats")]
+impl ComponentRegistrationStats {
+    /// Attempts that didn't register the type.
+    #[inline]
+    pub fn redundant(&self) -> u64 {
+        self.attempts - self.registrations
+    }
+}
+
+#[cfg(feature = "registration-stats")]
+impl RegistrationStats {
+    /// Counts of the component, all zeroes if it was never added.
+    #[inline]
+    pub fn stats(&self, id: ComponentId) -> ComponentRegistrationStats {
+        self.components.get(&id).copied().unwrap_or_default()
+    }
+
+    /// Iterates over the components and their counts, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, ComponentRegistrationStats)> + '_ {
+        self.components.iter().map(|(&id, &stats)| (id, stats))
+    }
+
+    fn record<T: ComponentAutoRegister>(&mut self, id: ComponentId, registered: bool) {
+        let stats = self.components.entry(id).or_insert_with(|| ComponentRegistrationStats {
+            name: type_name::<T>(),
+            ..Default::default()
+        });
+        stats.attempts += 1;
+        stats.registrations += u64::from(registered);
+    }
 }
 
 // macro_rules! wrapper_init {
@@ -41,7 +806,6 @@ pub fn register_on_add<T: ComponentAutoRegister>(
 // wrapper_init!(Ref<'_, T>,);
 // wrapper_init!(Mut<'_, T>,);
 // wrapper_init!(Option<T>,);
-// wrapper_init!(PhantomData<T>,);
 // wrapper_init!(NonSend<'_, T>,);
 // wrapper_init!(NonSendMut<'_, T>,);
 // wrapper_init!(Res<'_, T>, Resource);
@@ -49,3 +813,57 @@ pub fn register_on_add<T: ComponentAutoRegister>(
 // wrapper_init!(EventReader<'_, '_, T>, Event);
 // wrapper_init!(EventWriter<'_, T>, Event);
 // wrapper_init!(Deferred<'_, T>, SystemBuffer);
+
+/// Calls the user's `hook` of the component `T`. If it panics, logs an error naming the component
+/// and the hook, and resumes the panic with the original payload.
+#[doc(hidden)]
+pub fn call_hook<T: ?Sized>(hook: &str, call: impl FnOnce()) {
+    let Err(payload) = catch_unwind(AssertUnwindSafe(call)) else {
+        return;
+    };
+
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+    match message {
+        Some(message) => error!("`{hook}` hook of `{}` panicked: {message}", type_name::<T>()),
+        None => error!("`{hook}` hook of `{}` panicked", type_name::<T>()),
+    }
+    resume_unwind(payload);
+}
+
+/// Evaluating [`OK`](AssertZeroSized::OK) fails to compile if `T` is not zero-sized.
+/// Used by `#[component(register_only)]`.
+#[doc(hidden)]
+pub struct AssertZeroSized<T>(PhantomData<T>);
+
+impl<T> AssertZeroSized<T> {
+    pub const OK: () = assert!(
+        size_of::<T>() == 0,
+        "`register_only` components must be zero-sized"
+    );
+}
+
+/// Panics if the `require_preregistered` component `T` is not registered.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_preregistered<T: ComponentAutoRegister>(world: &DeferredWorld) {
+    let registered = world
+        .get_resource::<RegisteredTypes>()
+        .is_some_and(RegisteredTypes::is_registered::<T>);
+    if !registered {
+        let name = type_name::<T>();
+        panic!("`{name}` requires pre-registration, but was added before it was registered. Call `app.register::<{name}>()` at startup");
+    }
+}
+
+/// Queues removal of the `register_only` component `T` from the `entity`.
+#[doc(hidden)]
+pub fn remove_register_only<T: Component>(mut world: DeferredWorld, entity: Entity) {
+    world.commands().add(move |world: &mut World| {
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            entity.remove::<T>();
+        }
+    });
+}
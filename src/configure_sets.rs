@@ -0,0 +1,140 @@
+//! Configuring system sets
+
+use bevy_consumable_event::{Consume, ConsumableEvents};
+use bevy_ecs::{
+    change_detection::Mut,
+    event::Event,
+    schedule::{InternedScheduleLabel, IntoSystemSetConfigs, ScheduleLabel, Schedules, SystemSetConfigs},
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::{debug, error, warn};
+
+use crate::add_systems::{is_adding_systems, AddSystemsError};
+
+/// Configures system sets of the schedule with [`Schedules::configure_sets`] during
+/// [`AddingSystems`](crate::add_systems::AddingSystems) schedule, before the requested systems are added.
+/// Use it to change ordering or run conditions of sets at runtime.
+/// Should use [`ConsumableEventWriter`](bevy_consumable_event::ConsumableEventWriter) to write events.
+///
+/// Events targeting [`AddingSystems`](crate::add_systems::AddingSystems) or the schedule in
+/// [`AddingSystemsLabel`](crate::add_systems::AddingSystemsLabel) are skipped with an error,
+/// since that schedule is running while the events are applied.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::prelude::*;
+///
+/// #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct Simulation;
+///
+/// #[derive(Resource, Default)]
+/// struct Steps(u32);
+///
+/// #[derive(Resource, Default)]
+/// struct Paused(bool);
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.init_resource::<Steps>().init_resource::<Paused>();
+/// app.add_systems(Update, (|mut steps: ResMut<Steps>| steps.0 += 1).in_set(Simulation));
+///
+/// app.update();
+/// app.world_mut().configure_sets(Update, Simulation.run_if(|paused: Res<Paused>| !paused.0));
+/// app.world_mut().resource_mut::<Paused>().0 = true;
+///
+/// // The configuration is applied after `Update`
+/// app.update();
+/// app.update();
+/// assert_eq!(app.world().resource::<Steps>().0, 2);
+/// ```
+#[derive(Event)]
+pub struct ConfigureSets(InternedScheduleLabel, SystemSetConfigs);
+
+impl ConfigureSets {
+    /// Creates the event configuring the `sets` of the `schedule`.
+    pub fn new(schedule: impl ScheduleLabel, sets: impl IntoSystemSetConfigs) -> Self {
+        ConfigureSets(schedule.intern(), sets.into_configs())
+    }
+
+    /// The schedule the sets are configured in.
+    #[inline]
+    pub fn schedule(&self) -> InternedScheduleLabel {
+        self.0
+    }
+}
+
+/// Consumes all [`ConfigureSets`] events, and configures the sets.
+/// This should *only* run during [`AddingSystems`](crate::add_systems::AddingSystems) schedules,
+/// in [`RegisterInWorldSet::ApplyConfigureSets`](crate::add_systems::RegisterInWorldSet::ApplyConfigureSets).
+/// If you're not using [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin),
+/// add this system to the [`AddingSystems`](crate::add_systems::AddingSystems) schedule,
+/// before [`add_requested_systems`](crate::add_systems::add_requested_systems).
+///
+/// If [`Schedules`] doesn't exist, a warning is logged and the events are left unconsumed.
+pub fn apply_requested_set_configs(world: &mut World) {
+    apply_set_configs(world, false);
+}
+
+/// Configures the requested sets, leaving the events for schedules that are not in [`Schedules`]
+/// if `postpone_missing` is `true`, like [`drain_system_additions`](crate::add_systems::drain_system_additions) does.
+pub(crate) fn apply_set_configs(world: &mut World, postpone_missing: bool) {
+    let has_schedules = world.contains_resource::<Schedules>();
+    let Some(mut events) = world.get_resource_mut::<ConsumableEvents<ConfigureSets>>() else {
+        return;
+    };
+    if !has_schedules {
+        if events.read().next().is_some() {
+            warn!("`Schedules` doesn't exist, configuring the requested sets is postponed until it's back");
+        }
+        return;
+    }
+
+    world.resource_scope(|world, mut schedules: Mut<Schedules>| {
+        let mut events = world.resource_mut::<ConsumableEvents<ConfigureSets>>();
+        let requested: Vec<_> = events
+            .read()
+            .filter(|event| !postpone_missing || schedules.contains(event.schedule()))
+            .map(Consume::consume)
+            .collect();
+
+        for ConfigureSets(schedule, sets) in requested {
+            if is_adding_systems(world, schedule) {
+                error!("{}", AddSystemsError::AddingSystemsSchedule);
+                continue;
+            }
+            debug!("Configuring sets of {schedule:?}");
+            schedules.configure_sets(schedule, sets);
+        }
+    });
+}
+
+/// Convenience trait to send [`ConfigureSets`] events.
+pub trait WorldConfigureSets {
+    /// Sends the [`ConfigureSets`] event.
+    fn configure_sets(&mut self, schedule: impl ScheduleLabel, sets: impl IntoSystemSetConfigs);
+}
+
+impl WorldConfigureSets for DeferredWorld<'_> {
+    /// If [`ConsumableEvents<ConfigureSets>`] doesn't exist yet, the event is sent through commands,
+    /// which initialize the resource first.
+    fn configure_sets(&mut self, schedule: impl ScheduleLabel, sets: impl IntoSystemSetConfigs) {
+        let event = ConfigureSets::new(schedule, sets);
+        match self.get_resource_mut::<ConsumableEvents<ConfigureSets>>() {
+            Some(mut events) => events.send(event),
+            None => self.commands().add(|world: &mut World| {
+                world
+                    .get_resource_or_insert_with::<ConsumableEvents<ConfigureSets>>(Default::default)
+                    .send(event);
+            }),
+        }
+    }
+}
+
+impl WorldConfigureSets for World {
+    /// Initializes [`ConsumableEvents<ConfigureSets>`] if it doesn't exist yet.
+    fn configure_sets(&mut self, schedule: impl ScheduleLabel, sets: impl IntoSystemSetConfigs) {
+        self.get_resource_or_insert_with::<ConsumableEvents<ConfigureSets>>(Default::default)
+            .send(ConfigureSets::new(schedule, sets));
+    }
+}
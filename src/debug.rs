@@ -0,0 +1,151 @@
+//! Human-readable reports of the registration state
+
+use std::fmt::Write;
+
+use bevy_ecs::world::World;
+
+use crate::{add_systems::RuntimeAddedSystems, RegisteredTypes, RegistrationOrigin};
+
+/// Produces a human-readable report of the registration state of the world:
+/// names of the registered types, with the tick and the [origin](RegistrationOrigin) of their registration
+/// if they were recorded, and names of the systems added
+/// by [`add_requested_systems`](crate::add_systems::add_requested_systems), grouped by schedule,
+/// with the type they are [attributed](crate::add_systems::AddSystems::origin) to.
+/// Everything is sorted, except systems inside a schedule, which are in the order they were added in.
+///
+/// Systems are only listed if [`RuntimeAddedSystems`] exists. With the `registration-stats` feature,
+/// counts of `RegistrationStats` are listed too, if there are any.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::any::{type_name, type_name_of_val};
+/// use bevy_register_in_world::{debug::dump, prelude::*, RegisteredTypes};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(Update, (system_a, system_b));
+///     }
+/// }
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Bar;
+///
+/// impl RegisterInWorld for Bar {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(Last, system_a);
+///     }
+/// }
+///
+/// fn system_a() {}
+/// fn system_b() {}
+///
+/// fn main() {
+///     let mut app = App::new();
+///     app.add_plugins(RegisterInWorldPlugin::default());
+///     app.register::<Foo>();
+///     let entity = app.world_mut().spawn(Bar).id();
+///     app.world_mut().add_systems(Update, system_b);
+///     app.update();
+///
+///     let (foo, bar) = (type_name::<Foo>(), type_name::<Bar>());
+///     let (a, b) = (type_name_of_val(&system_a), type_name_of_val(&system_b));
+///     let registered = app.world().resource::<RegisteredTypes>();
+///     let foo_tick = registered.registration_tick::<Foo>().unwrap().get();
+///     let bar_tick = registered.registration_tick::<Bar>().unwrap().get();
+///     assert_eq!(dump(app.world()), format!("\
+/// Registered types (2):
+///   {bar}: tick {bar_tick}, {bar} added to {entity}
+///   {foo}: tick {foo_tick}, manual
+/// Runtime-added systems:
+///   Last (1):
+///     {a} (from {bar})
+///   Update (3):
+///     {a} (from {foo})
+///     {b} (from {foo})
+///     {b}
+/// Registration stats:
+///   {bar}: 1 attempts, 1 registrations
+/// "));
+/// }
+/// ```
+pub fn dump(world: &World) -> String {
+    let mut report = String::new();
+
+    let mut types: Vec<_> = world
+        .get_resource::<RegisteredTypes>()
+        .map(|registered| {
+            registered
+                .types
+                .iter()
+                .map(|(id, &name)| (name, registered.ticks.get(id), registered.origins.get(id)))
+                .collect()
+        })
+        .unwrap_or_default();
+    types.sort_unstable_by_key(|&(name, _, _)| name);
+    let _ = writeln!(report, "Registered types ({}):", types.len());
+    for (name, tick, origin) in types {
+        let _ = write!(report, "  {name}");
+        if let Some(tick) = tick {
+            let _ = write!(report, ": tick {}", tick.get());
+        }
+        match origin {
+            Some(RegistrationOrigin::Manual) => {
+                let _ = write!(report, ", manual");
+            }
+            Some(RegistrationOrigin::Component { entity, component, name }) => {
+                let component = world.components().get_name(*component).unwrap_or("<unknown>");
+                let _ = write!(report, ", {component} added to {entity}");
+                if let Some(name) = name {
+                    let _ = write!(report, " ({name})");
+                }
+            }
+            None => {}
+        }
+        let _ = writeln!(report);
+    }
+
+    if let Some(runtime_added) = world.get_resource::<RuntimeAddedSystems>() {
+        let mut schedules: Vec<_> = runtime_added
+            .iter()
+            .map(|(schedule, systems)| (format!("{schedule:?}"), systems, runtime_added.origins(schedule)))
+            .collect();
+        schedules.sort_unstable_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+        let _ = writeln!(report, "Runtime-added systems:");
+        for (schedule, systems, origins) in schedules {
+            let _ = writeln!(report, "  {schedule} ({}):", systems.len());
+            for (index, system) in systems.iter().enumerate() {
+                match origins.get(index).copied().flatten() {
+                    Some(origin) => {
+                        let _ = writeln!(report, "    {system} (from {origin})");
+                    }
+                    None => {
+                        let _ = writeln!(report, "    {system}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "registration-stats")]
+    if let Some(stats) = world.get_resource::<crate::component::RegistrationStats>() {
+        let mut stats: Vec<_> = stats.iter().map(|(_, stats)| stats).collect();
+        if !stats.is_empty() {
+            stats.sort_unstable_by_key(|stats| stats.name);
+            let _ = writeln!(report, "Registration stats:");
+            for stats in stats {
+                let _ = writeln!(
+                    report,
+                    "  {}: {} attempts, {} registrations",
+                    stats.name, stats.attempts, stats.registrations
+                );
+            }
+        }
+    }
+
+    report
+}
@@ -0,0 +1,344 @@
+//! Registrations that are postponed until [`AddingSystems`](crate::add_systems::AddingSystems)
+
+use bevy_ecs::{
+    system::Resource,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::warn;
+use std::{
+    any::{type_name, TypeId},
+    fmt,
+};
+
+use crate::{
+    run_registration, RegisterInWorld, RegisteredTypes, RegistrationOrigin, RegistrationStage,
+};
+
+/// Queue of the registrations of types in [`RegistrationStage::Deferred`],
+/// applied by [`apply_deferred_registrations`].
+/// Initialized by [`RegisterInWorldPlugin`](crate::app::RegisterInWorldPlugin).
+/// If it doesn't exist, deferred types are registered immediately.
+///
+/// A registration stays queued while [`RegisterInWorld::ready`] of its type returns `false`.
+/// If it's still not ready after
+/// [`max_registration_attempts`](DeferredRegistrations::set_max_registration_attempts) runs of
+/// [`apply_deferred_registrations`], a warning naming the type is logged, and the registration is dropped if
+/// [`drop_exceeded`](DeferredRegistrations::set_drop_exceeded) is set.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use bevy_utils::tracing::Level;
+/// use bevy_register_in_world::{
+///     deferred::DeferredRegistrations,
+///     prelude::*,
+///     test_utils::{capture_logs, RegistrationTestHarness},
+///     RegistrationStage,
+/// };
+///
+/// #[derive(Resource)]
+/// struct Assets;
+///
+/// #[derive(Resource)]
+/// struct FooSetUp;
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.commands().insert_resource(FooSetUp);
+///     }
+///
+///     fn stage() -> RegistrationStage {
+///         RegistrationStage::Deferred
+///     }
+///
+///     fn ready(world: &World) -> bool {
+///         world.contains_resource::<Assets>()
+///     }
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness
+///     .world_mut()
+///     .resource_mut::<DeferredRegistrations>()
+///     .set_max_registration_attempts(Some(3));
+/// harness.register::<Foo>();
+///
+/// let logs = capture_logs(Level::WARN, || {
+///     harness.flush_registrations().flush_registrations();
+/// });
+/// assert!(logs.is_empty());
+///
+/// let logs = capture_logs(Level::WARN, || {
+///     harness.flush_registrations();
+/// });
+/// assert_eq!(
+///     logs,
+///     [format!("`{}` is still not ready to be registered after 3 attempts", std::any::type_name::<Foo>())],
+/// );
+/// assert_eq!(harness.world().resource::<DeferredRegistrations>().len(), 1);
+///
+/// // Not dropped, so `register` runs once it's ready
+/// assert!(!harness.world().contains_resource::<FooSetUp>());
+/// harness.world_mut().insert_resource(Assets);
+/// harness.flush_registrations();
+/// assert!(harness.world().contains_resource::<FooSetUp>());
+/// assert!(harness.world().resource::<DeferredRegistrations>().is_empty());
+/// ```
+///
+/// With [`set_drop_exceeded`](DeferredRegistrations::set_drop_exceeded), the stuck registration is dropped
+/// after the warning, and the type is no longer marked as registered, so it can be registered again later.
+/// The limit applies to the registrations that are already queued too, so lowering it below their attempts
+/// drops them on the next run.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{
+///     deferred::DeferredRegistrations,
+///     prelude::*,
+///     test_utils::RegistrationTestHarness,
+///     RegistrationStage,
+/// };
+///
+/// #[derive(Resource)]
+/// struct Assets;
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+///
+///     fn stage() -> RegistrationStage {
+///         RegistrationStage::Deferred
+///     }
+///
+///     fn ready(world: &World) -> bool {
+///         world.contains_resource::<Assets>()
+///     }
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.world_mut().resource_mut::<DeferredRegistrations>().set_drop_exceeded(true);
+/// harness.register::<Foo>();
+///
+/// harness.flush_registrations().flush_registrations().flush_registrations();
+/// assert_eq!(harness.world().resource::<DeferredRegistrations>().len(), 1);
+///
+/// harness
+///     .world_mut()
+///     .resource_mut::<DeferredRegistrations>()
+///     .set_max_registration_attempts(Some(2));
+/// harness.flush_registrations();
+/// assert!(harness.world().resource::<DeferredRegistrations>().is_empty());
+/// assert!(!harness.world().is_registered::<Foo>());
+///
+/// harness.world_mut().insert_resource(Assets);
+/// harness.register::<Foo>();
+/// harness.flush_registrations();
+/// harness.assert_registered::<Foo>();
+/// assert!(harness.world().resource::<DeferredRegistrations>().is_empty());
+/// ```
+#[derive(Resource, Default)]
+pub struct DeferredRegistrations {
+    queued: Vec<QueuedRegistration>,
+    max_registration_attempts: Option<u32>,
+    drop_exceeded: bool,
+}
+
+/// Runs the registration of a queued type.
+type Register = fn(DeferredWorld, RegistrationOrigin);
+
+struct QueuedRegistration {
+    id: TypeId,
+    name: &'static str,
+    register: Register,
+    ready: fn(&World) -> bool,
+    origin: RegistrationOrigin,
+    attempts: u32,
+    warned: bool,
+}
+
+impl DeferredRegistrations {
+    /// Amount of queued registrations.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queued.len()
+    }
+
+    /// Returns `true` if no registrations are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.queued.is_empty()
+    }
+
+    /// Names of the types whose registrations are queued, in the order they are applied in.
+    pub fn queued_type_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.queued.iter().map(|queued| queued.name)
+    }
+
+    /// Sets after how many runs of [`apply_deferred_registrations`] with the type not being
+    /// [ready](RegisterInWorld::ready) a warning is logged for a queued registration.
+    /// `None`, the default, never warns. Applies to the registrations that are already queued too.
+    pub fn set_max_registration_attempts(&mut self, max: Option<u32>) {
+        self.max_registration_attempts = max;
+    }
+
+    /// Sets if registrations are dropped from the queue after exceeding their maximum amount of attempts.
+    /// `false` by default.
+    pub fn set_drop_exceeded(&mut self, drop: bool) {
+        self.drop_exceeded = drop;
+    }
+
+    fn push<T: RegisterInWorld>(&mut self, origin: RegistrationOrigin) {
+        self.queued.push(QueuedRegistration {
+            id: TypeId::of::<T>(),
+            name: type_name::<T>(),
+            register: |world, origin| run_registration::<T>(world, origin, T::register),
+            ready: T::ready,
+            origin,
+            attempts: 0,
+            warned: false,
+        });
+    }
+}
+
+impl fmt::Debug for DeferredRegistrations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeferredRegistrations")
+            .field("queued", &self.queued_type_names().collect::<Vec<_>>())
+            .field("max_registration_attempts", &self.max_registration_attempts)
+            .field("drop_exceeded", &self.drop_exceeded)
+            .finish()
+    }
+}
+
+/// Queues the registration of `T` if it's in [`RegistrationStage::Deferred`] and the queue exists.
+/// Returns `true` if the registration was queued.
+pub(crate) fn queue_deferred<T: RegisterInWorld>(
+    world: &mut DeferredWorld,
+    origin: RegistrationOrigin,
+) -> bool {
+    if T::stage() != RegistrationStage::Deferred {
+        return false;
+    }
+    let Some(mut queue) = world.get_resource_mut::<DeferredRegistrations>() else {
+        return false;
+    };
+    queue.push::<T>(origin);
+    true
+}
+
+/// Runs the queued registrations, in the order they were queued in, flushing commands after each one.
+/// Runs in [`AddingSystems`](crate::add_systems::AddingSystems) before the requested systems are added,
+/// in [`RegisterInWorldSet::ApplyDeferredRegistrations`](crate::add_systems::RegisterInWorldSet::ApplyDeferredRegistrations).
+/// Registrations queued while they run are applied on the next run.
+///
+/// Registrations of types that are not [ready](RegisterInWorld::ready) stay in the queue, counting an attempt,
+/// see [`DeferredRegistrations`] for what happens when there are too many of them.
+///
+/// If a registration panics, the registrations after it stay queued for the next run.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{
+///     deferred::{apply_deferred_registrations, DeferredRegistrations},
+///     prelude::*,
+///     test_utils::RegistrationTestHarness,
+///     RegistrationStage,
+/// };
+/// use std::panic::{catch_unwind, AssertUnwindSafe};
+///
+/// struct Panics;
+///
+/// impl RegisterInWorld for Panics {
+///     fn register(_world: DeferredWorld) {
+///         panic!("failed to register");
+///     }
+///
+///     fn stage() -> RegistrationStage {
+///         RegistrationStage::Deferred
+///     }
+/// }
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+///
+///     fn stage() -> RegistrationStage {
+///         RegistrationStage::Deferred
+///     }
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.register::<Panics>().register::<Foo>();
+///
+/// let world = harness.world_mut();
+/// assert!(catch_unwind(AssertUnwindSafe(|| apply_deferred_registrations(world))).is_err());
+/// assert_eq!(
+///     world.resource::<DeferredRegistrations>().queued_type_names().collect::<Vec<_>>(),
+///     [std::any::type_name::<Foo>()],
+/// );
+/// ```
+pub fn apply_deferred_registrations(world: &mut World) {
+    let Some(mut queue) = world.get_resource_mut::<DeferredRegistrations>() else {
+        return;
+    };
+    let queued = std::mem::take(&mut queue.queued);
+    let (max_attempts, drop_exceeded) = (queue.max_registration_attempts, queue.drop_exceeded);
+
+    let mut applying = ApplyingRegistrations {
+        world,
+        remaining: queued.into_iter(),
+        postponed: Vec::new(),
+    };
+    for mut queued in applying.remaining.by_ref() {
+        let world = &mut *applying.world;
+        if (queued.ready)(world) {
+            (queued.register)(world.into(), queued.origin);
+            world.flush_commands();
+            continue;
+        }
+
+        queued.attempts += 1;
+        if max_attempts.is_some_and(|max| queued.attempts >= max) {
+            if !queued.warned {
+                queued.warned = true;
+                warn!(
+                    "`{}` is still not ready to be registered after {} attempts",
+                    queued.name, queued.attempts,
+                );
+            }
+            if drop_exceeded {
+                // Deferred types are marked as registered when they are queued
+                if let Some(mut registered) = world.get_resource_mut::<RegisteredTypes>() {
+                    registered.unmark_id(queued.id);
+                }
+                continue;
+            }
+        }
+        applying.postponed.push(queued);
+    }
+}
+
+/// Puts the postponed and not yet applied registrations back into [`DeferredRegistrations`] when dropped,
+/// ahead of the ones queued while applying, so a panicking registration doesn't lose the ones after it.
+struct ApplyingRegistrations<'w> {
+    world: &'w mut World,
+    remaining: std::vec::IntoIter<QueuedRegistration>,
+    postponed: Vec<QueuedRegistration>,
+}
+
+impl Drop for ApplyingRegistrations<'_> {
+    fn drop(&mut self) {
+        let mut queued = std::mem::take(&mut self.postponed);
+        queued.extend(&mut self.remaining);
+        if let Some(mut queue) = self.world.get_resource_mut::<DeferredRegistrations>() {
+            queued.append(&mut queue.queued);
+            queue.queued = queued;
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Interop with `bevy_diagnostic`
+
+use bevy_app::{App, Plugin};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{schedule::IntoSystemConfigs, system::Res};
+
+use crate::{
+    add_systems::{RegisterInWorldSet, SystemAdditionStats},
+    app::AddingSystemsApp,
+    RegisteredTypes,
+};
+
+/// Adds diagnostics of registrations: [`REGISTERED_TYPES`](Self::REGISTERED_TYPES), the amount of types
+/// in [`RegisteredTypes`], and [`SYSTEMS_ADDED`](Self::SYSTEMS_ADDED), the amount of systems added
+/// during the frame, from [`SystemAdditionStats::frame`].
+///
+/// The diagnostics are measured by a system in [`AddingSystems`](crate::add_systems::AddingSystems),
+/// after [`RegisterInWorldSet::ApplyAddSystems`]. Add this plugin after
+/// [`RegisterInWorldPlugin`](crate::app::RegisterInWorldPlugin), so the system ends up in the schedule
+/// from [`AddingSystemsLabel`](crate::add_systems::AddingSystemsLabel).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_diagnostic::{DiagnosticPath, DiagnosticsStore};
+/// use bevy_register_in_world::{diagnostic::RegisterInWorldDiagnosticsPlugin, prelude::*};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(Update, (|| {}, || {}));
+///     }
+/// }
+///
+/// fn value(app: &App, path: &DiagnosticPath) -> Option<f64> {
+///     app.world().resource::<DiagnosticsStore>().get(path).unwrap().value()
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((RegisterInWorldPlugin::default(), RegisterInWorldDiagnosticsPlugin));
+///
+/// app.update();
+/// assert_eq!(value(&app, &RegisterInWorldDiagnosticsPlugin::REGISTERED_TYPES), Some(0.0));
+/// assert_eq!(value(&app, &RegisterInWorldDiagnosticsPlugin::SYSTEMS_ADDED), Some(0.0));
+///
+/// app.register::<Foo>();
+/// app.update();
+/// assert_eq!(value(&app, &RegisterInWorldDiagnosticsPlugin::REGISTERED_TYPES), Some(1.0));
+/// assert_eq!(value(&app, &RegisterInWorldDiagnosticsPlugin::SYSTEMS_ADDED), Some(2.0));
+///
+/// app.update();
+/// assert_eq!(value(&app, &RegisterInWorldDiagnosticsPlugin::SYSTEMS_ADDED), Some(0.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RegisterInWorldDiagnosticsPlugin;
+
+impl RegisterInWorldDiagnosticsPlugin {
+    /// The amount of registered types.
+    pub const REGISTERED_TYPES: DiagnosticPath = DiagnosticPath::const_new("register_in_world/registered_types");
+    /// The amount of systems added during the frame, including the ones added between fixed steps.
+    pub const SYSTEMS_ADDED: DiagnosticPath = DiagnosticPath::const_new("register_in_world/systems_added");
+
+    /// Measures the diagnostics, the ones whose resources don't exist are skipped.
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        registered: Option<Res<RegisteredTypes>>,
+        stats: Option<Res<SystemAdditionStats>>,
+    ) {
+        if let Some(registered) = registered {
+            diagnostics.add_measurement(&Self::REGISTERED_TYPES, || registered.len() as f64);
+        }
+        if let Some(stats) = stats {
+            diagnostics.add_measurement(&Self::SYSTEMS_ADDED, || stats.frame as f64);
+        }
+    }
+}
+
+impl Plugin for RegisterInWorldDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::REGISTERED_TYPES))
+            .register_diagnostic(Diagnostic::new(Self::SYSTEMS_ADDED))
+            .add_to_adding_systems(Self::diagnostic_system.after(RegisterInWorldSet::ApplyAddSystems));
+    }
+}
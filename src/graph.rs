@@ -0,0 +1,162 @@
+//! Recording which types register which other types
+
+use std::any::{type_name, TypeId};
+
+use bevy_ecs::{
+    system::Resource,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::hashbrown::HashSet;
+
+use crate::{RegisterExtension, RegisterInWorld, RegisteredTypes, TypeIdMap};
+
+/// Dependency edges between registered types, recorded by [`RegisterAlso::register_also`].
+/// An edge `T -> U` means that `U` was registered as a dependency of `T`, while `T` was being registered,
+/// the innermost registration on the stack of [`RegisteredTypes`].
+/// Initialized by [`RegisterInWorldPlugin`](crate::app::RegisterInWorldPlugin).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{graph::{RegisterAlso, RegistrationGraph}, prelude::*};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.register_also::<Bar>().register_also::<Baz>();
+///     }
+/// }
+///
+/// struct Bar;
+///
+/// impl RegisterInWorld for Bar {
+///     fn register(mut world: DeferredWorld) {
+///         world.register_also::<Baz>();
+///     }
+/// }
+///
+/// struct Baz;
+///
+/// impl RegisterInWorld for Baz {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegistrationGraph>();
+/// world.register::<Foo>();
+///
+/// assert!(world.is_registered::<Baz>());
+/// let graph = world.resource::<RegistrationGraph>();
+/// assert!(graph.depends_on::<Foo, Bar>());
+/// assert!(graph.depends_on::<Foo, Baz>());
+/// assert!(graph.depends_on::<Bar, Baz>());
+/// assert!(!graph.depends_on::<Baz, Foo>());
+/// assert_eq!(graph.len(), 3);
+/// ```
+///
+/// A registration that panics stops being the one edges are recorded from, even if the panic is caught.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use bevy_register_in_world::{graph::{RegisterAlso, RegistrationGraph}, prelude::*};
+///
+/// struct Panicking;
+///
+/// impl RegisterInWorld for Panicking {
+///     fn register(_world: DeferredWorld) {
+///         panic!("failed to register");
+///     }
+/// }
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegistrationGraph>();
+///
+/// let result = catch_unwind(AssertUnwindSafe(|| world.register::<Panicking>()));
+/// assert!(result.is_err());
+///
+/// world.register_also::<Foo>();
+/// assert!(!world.resource::<RegistrationGraph>().depends_on::<Panicking, Foo>());
+/// assert!(world.resource::<RegistrationGraph>().is_empty());
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct RegistrationGraph {
+    edges: HashSet<(TypeId, TypeId)>,
+    names: TypeIdMap<&'static str>,
+}
+
+impl RegistrationGraph {
+    /// Returns `true` if `U` was registered as a dependency of `T`.
+    #[inline]
+    pub fn depends_on<T: 'static, U: 'static>(&self) -> bool {
+        self.edges.contains(&(TypeId::of::<T>(), TypeId::of::<U>()))
+    }
+
+    /// Iterates over names of the types of every `T -> U` edge, in no particular order.
+    pub fn edges(&self) -> impl Iterator<Item = (&'static str, &'static str)> + '_ {
+        self.edges
+            .iter()
+            .map(|(from, to)| (self.names[from], self.names[to]))
+    }
+
+    /// Amount of edges.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns `true` if there are no edges.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    fn add_edge<U: 'static>(&mut self, (from, from_name): (TypeId, &'static str)) {
+        self.names.insert(from, from_name);
+        self.names.insert(TypeId::of::<U>(), type_name::<U>());
+        self.edges.insert((from, TypeId::of::<U>()));
+    }
+}
+
+/// Registering dependencies of a type from its [`RegisterInWorld::register`],
+/// recording them in [`RegistrationGraph`] if it exists.
+pub trait RegisterAlso {
+    /// Registers `U`, the same as [`RegisterExtension::register`], and records the edge
+    /// from the type that is currently being registered to `U` in [`RegistrationGraph`].
+    /// The edge is recorded even if `U` is already registered.
+    fn register_also<U: RegisterInWorld>(&mut self) -> &mut Self;
+}
+
+impl RegisterAlso for DeferredWorld<'_> {
+    fn register_also<U: RegisterInWorld>(&mut self) -> &mut Self {
+        let from = self
+            .get_resource::<RegisteredTypes>()
+            .and_then(RegisteredTypes::current_registration);
+        if let Some((from, mut graph)) = from.zip(self.get_resource_mut::<RegistrationGraph>()) {
+            graph.add_edge::<U>(from);
+        }
+        self.register::<U>();
+        self
+    }
+}
+
+impl RegisterAlso for World {
+    fn register_also<U: RegisterInWorld>(&mut self) -> &mut Self {
+        let from = self
+            .get_resource::<RegisteredTypes>()
+            .and_then(RegisteredTypes::current_registration);
+        if let Some((from, mut graph)) = from.zip(self.get_resource_mut::<RegistrationGraph>()) {
+            graph.add_edge::<U>(from);
+        }
+        self.register::<U>();
+        self
+    }
+}
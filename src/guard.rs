@@ -0,0 +1,147 @@
+//! Windows of the frame during which registrations are not expected
+
+use bevy_ecs::{system::Resource, world::World};
+
+/// Marks "no-registration windows". Any registration happening while a window is open
+/// logs a warning naming the type, or panics if the guard is [strict](RegistrationGuard::is_strict).
+/// Registrations that are deduplicated don't count.
+///
+/// Windows can be opened and closed with [`begin`](RegistrationGuard::begin) and
+/// [`end`](RegistrationGuard::end), or from systems with [`ResMut<RegistrationGuard>`](bevy_ecs::system::ResMut).
+/// Windows nest, registrations are allowed again only after every opened window was closed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{guard::RegistrationGuard, prelude::*};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+///
+/// RegistrationGuard::begin(&mut world);
+/// // Logs a warning, but still registers
+/// world.register::<Foo>();
+/// RegistrationGuard::end(&mut world);
+///
+/// assert!(world.is_registered::<Foo>());
+/// ```
+///
+/// In strict mode, registering inside a window panics.
+///
+/// ```should_panic
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{guard::RegistrationGuard, prelude::*};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resource(RegistrationGuard::strict());
+///
+/// RegistrationGuard::begin(&mut world);
+/// world.spawn(Foo);
+/// ```
+///
+/// The guard is checked before the type is marked as registered, so if the panic is caught,
+/// the type is still unregistered and can be registered once the window is closed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use bevy_register_in_world::{guard::RegistrationGuard, prelude::*};
+///
+/// #[derive(Resource, Default)]
+/// struct Count(u32);
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Count>().0 += 1;
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Count>();
+/// world.insert_resource(RegistrationGuard::strict());
+///
+/// RegistrationGuard::begin(&mut world);
+/// let result = catch_unwind(AssertUnwindSafe(|| world.register::<Foo>()));
+/// assert!(result.is_err());
+/// assert!(!world.is_registered::<Foo>());
+/// RegistrationGuard::end(&mut world);
+///
+/// world.register::<Foo>();
+/// assert!(world.is_registered::<Foo>());
+/// assert_eq!(world.resource::<Count>().0, 1);
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RegistrationGuard {
+    open_windows: u32,
+    strict: bool,
+}
+
+impl RegistrationGuard {
+    /// Creates the guard that panics on registrations inside windows.
+    pub fn strict() -> Self {
+        Self {
+            open_windows: 0,
+            strict: true,
+        }
+    }
+
+    /// Opens a window in the world. Initializes the resource if it doesn't exist.
+    pub fn begin(world: &mut World) {
+        world
+            .get_resource_or_insert_with::<RegistrationGuard>(Default::default)
+            .open_window();
+    }
+
+    /// Closes a window in the world. Does nothing if the resource doesn't exist.
+    pub fn end(world: &mut World) {
+        if let Some(mut guard) = world.get_resource_mut::<RegistrationGuard>() {
+            guard.close_window();
+        }
+    }
+
+    /// Opens a window.
+    #[inline]
+    pub fn open_window(&mut self) {
+        self.open_windows += 1;
+    }
+
+    /// Closes a window. Does nothing if no windows are open.
+    #[inline]
+    pub fn close_window(&mut self) {
+        self.open_windows = self.open_windows.saturating_sub(1);
+    }
+
+    /// Returns `true` if any window is open.
+    #[inline]
+    pub fn is_in_window(&self) -> bool {
+        self.open_windows > 0
+    }
+
+    /// Returns `true` if registrations inside windows panic, instead of logging a warning.
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets the [strictness](RegistrationGuard::is_strict).
+    #[inline]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+}
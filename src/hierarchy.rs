@@ -0,0 +1,94 @@
+//! Interop with `bevy_hierarchy`
+
+use bevy_app::App;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    world::{DeferredWorld, OnAdd, OnInsert},
+};
+use bevy_hierarchy::Parent;
+
+use crate::{RegisterExtension, RegisterInWorld};
+
+/// Registers the parent component `P` when a child with the component `C` is attached to an entity with `P`.
+///
+/// Installs observers for [`OnAdd`] of `C`, covering children spawned under the parent,
+/// and for [`OnInsert`] of [`Parent`], covering children that have `C` and are attached later.
+/// Only the direct parent is checked.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_hierarchy::BuildWorldChildren;
+/// use bevy_register_in_world::{hierarchy::register_when_child_has, prelude::*};
+///
+/// #[derive(Component)]
+/// struct Vehicle;
+///
+/// impl RegisterInWorld for Vehicle {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// #[derive(Component)]
+/// struct Train;
+///
+/// impl RegisterInWorld for Train {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// #[derive(Component)]
+/// struct Wheel;
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// register_when_child_has::<Vehicle, Wheel>(&mut app);
+/// register_when_child_has::<Train, Wheel>(&mut app);
+///
+/// let world = app.world_mut();
+/// let vehicle = world.spawn(Vehicle).id();
+/// let wheel = world.spawn(Wheel).id();
+/// world.spawn_empty().with_children(|parent| {
+///     parent.spawn(Wheel);
+/// });
+/// world.flush();
+/// assert!(!world.is_registered::<Vehicle>());
+///
+/// // Attaching the child later
+/// world.entity_mut(wheel).set_parent(vehicle);
+/// world.flush();
+/// assert!(world.is_registered::<Vehicle>());
+///
+/// // Spawning the child under the parent
+/// assert!(!world.is_registered::<Train>());
+/// world.spawn(Train).with_children(|parent| {
+///     parent.spawn(Wheel);
+/// });
+/// world.flush();
+/// assert!(world.is_registered::<Train>());
+/// ```
+pub fn register_when_child_has<P, C>(app: &mut App)
+where
+    P: Component + RegisterInWorld,
+    C: Component,
+{
+    app.observe(|trigger: Trigger<OnAdd, C>, world: DeferredWorld| {
+        register_parent::<P>(world, trigger.entity());
+    });
+    app.observe(|trigger: Trigger<OnInsert, Parent>, world: DeferredWorld| {
+        if world.entity(trigger.entity()).contains::<C>() {
+            register_parent::<P>(world, trigger.entity());
+        }
+    });
+}
+
+/// Registers `P` if the parent of the `child` has it.
+fn register_parent<P: Component + RegisterInWorld>(mut world: DeferredWorld, child: Entity) {
+    let Some(parent) = world.get::<Parent>(child).map(Parent::get) else {
+        return;
+    };
+    if world.get::<P>(parent).is_some() {
+        world.register::<P>();
+    }
+}
@@ -0,0 +1,103 @@
+//! Eagerly registering the types that are known to be needed, leaving everything else lazy
+
+use bevy_ecs::{system::Resource, world::World};
+use bevy_utils::HashMap;
+
+use crate::{RegisterExtension, RegisterInWorld};
+
+/// Names of the types that should be registered eagerly, during
+/// finish of [`RegistrationPlugin`](crate::app::RegistrationPlugin), instead of when they are first needed.
+///
+/// Names are the ones from [`std::any::type_name`], the same ones that are listed by
+/// [`dump`](crate::debug::dump), so the hints can be collected from a previous run.
+/// Only the types with a register function in [`LazyRegistrations`] can be registered eagerly,
+/// other names are ignored.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RegisteredTypesHints {
+    names: Vec<String>,
+}
+
+impl RegisteredTypesHints {
+    /// Creates hints from the type names.
+    pub fn new<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Self {
+        names.into_iter().collect()
+    }
+
+    /// Adds the type name to the hints.
+    pub fn insert(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        if !self.contains(&name) {
+            self.names.push(name);
+        }
+    }
+
+    /// Returns wether the type name is hinted or not.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|hinted| hinted == name)
+    }
+
+    /// Hinted type names, in the order they were added in.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for RegisteredTypesHints {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut hints = Self::default();
+        for name in iter {
+            hints.insert(name);
+        }
+        hints
+    }
+}
+
+/// Register functions of the types, by their type names. Functions are recorded
+/// without being run, so the types are still registered lazily, unless they are hinted in [`RegisteredTypesHints`].
+///
+/// Populated by [`LazyRegistrationApp::register_lazy`](crate::app::LazyRegistrationApp::register_lazy).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LazyRegistrations {
+    registrations: HashMap<&'static str, fn(&mut World)>,
+}
+
+impl LazyRegistrations {
+    /// Records the register function of the type.
+    pub fn insert<T: RegisterInWorld>(&mut self) {
+        self.registrations
+            .insert(std::any::type_name::<T>(), |world| world.register::<T>());
+    }
+
+    /// Returns the register function of the type with the `name`, if it was recorded.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<fn(&mut World)> {
+        self.registrations.get(name).copied()
+    }
+
+    /// Amount of recorded register functions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.registrations.len()
+    }
+
+    /// Returns `true` if no register functions were recorded.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+}
+
+/// Register functions of the hinted types that were recorded, in the order of the hints.
+#[cfg(feature = "bevy_app")]
+pub(crate) fn hinted_registrations(world: &World) -> Vec<fn(&mut World)> {
+    let (Some(hints), Some(lazy)) = (
+        world.get_resource::<RegisteredTypesHints>(),
+        world.get_resource::<LazyRegistrations>(),
+    ) else {
+        return Vec::new();
+    };
+
+    hints.names().filter_map(|name| lazy.get(name)).collect()
+}
@@ -0,0 +1,176 @@
+//! Knowing whether a registration is running
+
+use std::{
+    any::TypeId,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+};
+
+use bevy_ecs::{
+    system::{Res, Resource},
+    world::World,
+};
+
+/// Depth of the registrations that are currently running. Incremented right before
+/// [`RegisterInWorld::register`](crate::RegisterInWorld::register) is called and decremented right after,
+/// even if it panics. Nested registrations increase the depth further.
+///
+/// Initialized by [`RegisterInWorldPlugin`](crate::app::RegisterInWorldPlugin) and
+/// by [`World::register`](crate::RegisterExtension::register). Registrations don't update it if it doesn't exist.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use bevy_register_in_world::{in_progress::{is_registering, RegistrationInProgress}, prelude::*};
+///
+/// struct Outer;
+///
+/// impl RegisterInWorld for Outer {
+///     fn register(mut world: DeferredWorld) {
+///         assert_eq!(world.resource::<RegistrationInProgress>().depth(), 1);
+///         world.register::<Inner>();
+///     }
+/// }
+///
+/// struct Inner;
+///
+/// impl RegisterInWorld for Inner {
+///     fn register(world: DeferredWorld) {
+///         assert_eq!(world.resource::<RegistrationInProgress>().depth(), 2);
+///     }
+/// }
+///
+/// struct Panicking;
+///
+/// impl RegisterInWorld for Panicking {
+///     fn register(_world: DeferredWorld) {
+///         panic!("failed to register");
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.register::<Outer>();
+/// assert!(world.is_registered::<Inner>());
+/// assert!(!is_registering(&world));
+///
+/// let result = catch_unwind(AssertUnwindSafe(|| world.register::<Panicking>()));
+/// assert!(result.is_err());
+/// assert!(!is_registering(&world));
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct RegistrationInProgress {
+    depth: Arc<AtomicU32>,
+}
+
+impl RegistrationInProgress {
+    /// How many registrations are currently running, counting nested ones.
+    #[inline]
+    pub fn depth(&self) -> u32 {
+        self.depth.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if a registration is currently running.
+    #[inline]
+    pub fn is_registering(&self) -> bool {
+        self.depth() > 0
+    }
+
+    /// Increments the depth, decrementing it back when the returned guard is dropped.
+    pub(crate) fn enter(&self) -> RegistrationInProgressGuard {
+        self.depth.fetch_add(1, Ordering::Relaxed);
+        RegistrationInProgressGuard(self.depth.clone())
+    }
+}
+
+/// Decrements the depth of [`RegistrationInProgress`] on drop, including drops during unwinding.
+pub(crate) struct RegistrationInProgressGuard(Arc<AtomicU32>);
+
+impl Drop for RegistrationInProgressGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Stack of the types whose registrations are currently running, innermost last.
+/// Entries are pushed with [`push`](RegistrationStack::push) and popped when the returned guard is dropped,
+/// so a registration that panics doesn't leave its entry behind if the panic is caught.
+#[derive(Debug, Default)]
+pub(crate) struct RegistrationStack(Arc<Mutex<Vec<TypeId>>>);
+
+impl RegistrationStack {
+    fn lock(&self) -> MutexGuard<'_, Vec<TypeId>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Pushes the `id`, truncating the stack back to its current length when the returned guard is dropped.
+    pub(crate) fn push(&self, id: TypeId) -> RegistrationStackGuard {
+        let mut stack = self.lock();
+        let depth = stack.len();
+        stack.push(id);
+        RegistrationStackGuard {
+            stack: self.0.clone(),
+            depth,
+        }
+    }
+
+    /// The innermost entry.
+    pub(crate) fn last(&self) -> Option<TypeId> {
+        self.lock().last().copied()
+    }
+
+    /// Copy of the entries, innermost last.
+    pub(crate) fn to_vec(&self) -> Vec<TypeId> {
+        self.lock().clone()
+    }
+}
+
+/// Truncates a [`RegistrationStack`] on drop, including drops during unwinding.
+pub(crate) struct RegistrationStackGuard {
+    stack: Arc<Mutex<Vec<TypeId>>>,
+    depth: usize,
+}
+
+impl Drop for RegistrationStackGuard {
+    fn drop(&mut self) {
+        self.stack
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .truncate(self.depth);
+    }
+}
+
+/// Returns `true` if a registration is currently running in the `world`.
+/// Returns `false` if [`RegistrationInProgress`] doesn't exist.
+#[inline]
+pub fn is_registering(world: &World) -> bool {
+    world
+        .get_resource::<RegistrationInProgress>()
+        .is_some_and(RegistrationInProgress::is_registering)
+}
+
+/// Run condition that is `true` unless a registration is currently running.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{in_progress::not_registering, prelude::*};
+///
+/// #[derive(Resource, Default)]
+/// struct Ran(bool);
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.init_resource::<Ran>();
+/// app.add_systems(Update, (|mut ran: ResMut<Ran>| ran.0 = true).run_if(not_registering()));
+///
+/// app.update();
+/// assert!(app.world().resource::<Ran>().0);
+/// ```
+pub fn not_registering() -> impl FnMut(Option<Res<RegistrationInProgress>>) -> bool + Clone {
+    |in_progress: Option<Res<RegistrationInProgress>>| {
+        !in_progress.is_some_and(|in_progress| in_progress.is_registering())
+    }
+}
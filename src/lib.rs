@@ -63,27 +63,83 @@
 
 extern crate self as bevy_register_in_world;
 
+pub mod add_observers;
+pub mod add_schedule;
 pub mod add_systems;
 #[cfg(feature = "bevy_app")]
 pub mod app;
+pub mod cached_system;
+pub mod callbacks;
 pub mod component;
+pub mod configure_sets;
+pub mod debug;
+pub mod deferred;
+#[cfg(feature = "diagnostic")]
+pub mod diagnostic;
+#[cfg(feature = "registration-graph")]
+pub mod graph;
+pub mod guard;
+#[cfg(feature = "bevy_hierarchy")]
+pub mod hierarchy;
+pub mod hints;
+pub mod in_progress;
+mod macros;
+pub mod registration_bundle;
+pub mod register_types;
+pub mod registration_world;
+pub mod registry_read;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod timings;
 // unsure if this is the right thing to do
 //pub mod system_param;
 
 use bevy_ecs::{
+    component::{ComponentId, Tick},
+    entity::Entity,
+    label::DynEq,
     system::Resource,
-    world::{DeferredWorld, World},
+    world::{DeferredWorld, FromWorld, World},
 };
-use bevy_utils::{hashbrown::HashSet, NoOpHash};
-use std::any::TypeId;
+use bevy_utils::{
+    hashbrown::{hash_map::Entry, HashMap, HashSet},
+    tracing::{debug, error, warn},
+    FixedState, Instant, NoOpHash,
+};
+#[cfg(feature = "trace")]
+use bevy_utils::tracing::info_span;
+use callbacks::{RegistrationCallbacks, RegistrationRecord};
+use deferred::queue_deferred;
+use guard::RegistrationGuard;
+use in_progress::{RegistrationInProgress, RegistrationStack};
+use timings::{RegistrationTiming, RegistrationTimings};
+use std::{
+    any::{type_name, TypeId},
+    borrow::Cow,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+use thiserror::Error;
+
+#[doc(hidden)]
+pub mod __macro_exports {
+    pub use bevy_ecs::world::DeferredWorld;
+}
 
 pub mod prelude {
     //! Prelude module
     
     pub use crate::{
         RegisterExtension, RegisterInWorld,
+        add_observers::{AddObserver, WorldAddObserver},
+        add_schedule::{AddSchedule, WorldAddSchedule},
         add_systems::{AddSystems, WorldAddSystems},
         component::ComponentAutoRegister,
+        configure_sets::{ConfigureSets, WorldConfigureSets},
+        register_types::{RegisterTypes, WorldRegisterTypes},
+        registration_world::RegistrationWorld,
     };
 
     #[cfg(feature = "bevy_app")]
@@ -91,7 +147,202 @@ pub mod prelude {
 }
 
 
+/// Derives [`RegisterInWorld`], adding systems listed in the `register` attribute 
+/// to the schedules during registration.
+///
+/// Every entry of the attribute is a schedule name in snake case, followed by the list of systems.
+/// Names of `bevy_app` schedules (`update`, `fixed_update`, ...) resolve to `bevy_app` schedules,
+/// other names resolve to the schedule label in camel case, that should be in scope.
+/// Systems that are written as plain function names are instantiated with 
+/// the generic parameters of the type, in the same order, other paths are used as written. 
+/// Every type parameter gets `Send + Sync + 'static` bounds.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use std::marker::PhantomData;
+/// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct MySchedule;
+///
+/// #[derive(RegisterInWorld)]
+/// #[register(update(system_a, system_b::<u8, B>), my_schedule(system_c))]
+/// struct Generic<A, B>(PhantomData<(A, B)>);
+///
+/// fn system_a<A, B>() {}
+/// fn system_b<A, B>() {}
+/// fn system_c<A, B>() {}
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.register::<Generic<i32, f32>>().flush_registrations();
+///
+/// harness.assert_system_in_schedule(Update, "system_a<i32, f32>");
+/// harness.assert_system_in_schedule(Update, "system_b<u8, f32>");
+/// harness.assert_system_in_schedule(MySchedule, "system_c<i32, f32>");
+/// ```
+///
+/// `init_resource = "Type"` entries initialize resources before the systems are added.
+/// The resource is initialized through commands, so it exists once the commands are flushed,
+/// which happens right after `register` when registering with [`World::register`](RegisterExtension::register).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use std::marker::PhantomData;
+/// use bevy_register_in_world::{prelude::*, test_utils::*};
+///
+/// #[derive(Resource)]
+/// struct Counter<A>(u32, PhantomData<A>);
+///
+/// impl<A> Default for Counter<A> {
+///     fn default() -> Self {
+///         Counter(0, PhantomData)
+///     }
+/// }
+///
+/// #[derive(ComponentAutoRegister, RegisterInWorld)]
+/// #[register(init_resource = "Counter<A>", update(count))]
+/// struct Generic<A: Send + Sync + 'static>(PhantomData<A>);
+///
+/// fn count<A: Send + Sync + 'static>(query: Query<&Generic<A>>, mut counter: ResMut<Counter<A>>) {
+///     counter.0 += query.iter().count() as u32;
+/// }
+///
+/// let mut app = registration_test_app();
+/// app.world_mut().spawn(Generic::<u8>(PhantomData));
+/// app.world_mut().flush();
+/// assert_registered::<Generic<u8>>(&app);
+/// assert_eq!(app.world().resource::<Counter<u8>>().0, 0);
+///
+/// app.update();
+/// app.update();
+/// assert_eq!(app.world().resource::<Counter<u8>>().0, 1);
+/// assert!(!app.world().contains_resource::<Counter<u16>>());
+/// ```
+///
+/// `observers(...)` entries add observers, with the same instantiation rules as systems.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use std::marker::PhantomData;
+/// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+///
+/// #[derive(Resource, Default)]
+/// struct Added(Vec<&'static str>);
+///
+/// #[derive(Component)]
+/// struct Thing<A: Send + Sync + 'static>(PhantomData<A>);
+///
+/// #[derive(RegisterInWorld)]
+/// #[register(observers(on_thing_added, on_thing_added::<u16>))]
+/// struct Generic<A>(PhantomData<A>);
+///
+/// fn on_thing_added<A: Send + Sync + 'static>(
+///     _trigger: Trigger<OnAdd, Thing<A>>,
+///     mut added: ResMut<Added>,
+/// ) {
+///     added.0.push(std::any::type_name::<A>());
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.world_mut().init_resource::<Added>();
+/// harness.register::<Generic<u8>>().flush_registrations();
+///
+/// harness.spawn_with(Thing::<u8>(PhantomData));
+/// harness.spawn_with(Thing::<u16>(PhantomData));
+/// harness.spawn_with(Thing::<u32>(PhantomData));
+/// assert_eq!(harness.world().resource::<Added>().0, ["u8", "u16"]);
+/// ```
+///
+/// `spawn(...)` entries spawn an entity with every listed bundle through commands, once per registration,
+/// for example a manager entity for every instantiation of a generic component.
+/// The expressions can use the generic parameters of the type.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use std::marker::PhantomData;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(Component)]
+/// struct EmitterManager<A: Send + Sync + 'static>(PhantomData<A>);
+///
+/// impl<A: Send + Sync + 'static> Default for EmitterManager<A> {
+///     fn default() -> Self {
+///         EmitterManager(PhantomData)
+///     }
+/// }
+///
+/// #[derive(ComponentAutoRegister, RegisterInWorld)]
+/// #[register(spawn(EmitterManager::<A>::default()))]
+/// struct Emitter<A: Send + Sync + 'static>(PhantomData<A>);
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// for _ in 0..5 {
+///     world.spawn(Emitter::<u8>(PhantomData));
+/// }
+/// world.flush();
+///
+/// let mut managers = world.query::<&EmitterManager<u8>>();
+/// assert_eq!(managers.iter(&world).count(), 1);
+///
+/// world.spawn(Emitter::<u16>(PhantomData));
+/// world.spawn(Emitter::<u16>(PhantomData));
+/// world.flush();
+///
+/// let mut managers = world.query_filtered::<(), Or<(With<EmitterManager<u8>>, With<EmitterManager<u16>>)>>();
+/// assert_eq!(managers.iter(&world).count(), 2);
+/// ```
+pub use bevy_register_in_world_macros::RegisterInWorld;
+
 /// Types that can be registered to the world.
+///
+/// # Wrappers
+///
+/// [`Arc<T>`], [`Box<T>`] and [`PhantomData<T>`] are registrable when `T` is. Registering a wrapper marks the wrapper's 
+/// own [`TypeId`] as registered and registers `T` through [`RegisterExtension::register`], 
+/// so `T` shares its slot with all the wrappers and `T::register` runs only once.
+///
+/// Arrays `[T; N]` work the same way: the element type is registered once, whatever `N` is,
+/// and every array length has its own [`TypeId`] that is marked as registered.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::{marker::PhantomData, sync::Arc};
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(Resource, Default)]
+/// struct Count(u32);
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Count>().0 += 1;
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Count>();
+///
+/// world.register::<Arc<Foo>>();
+/// world.register::<Box<Foo>>();
+/// world.register::<Foo>();
+/// world.register::<PhantomData<Foo>>();
+/// world.register::<[Foo; 4]>();
+/// world.register::<[Foo; 8]>();
+///
+/// let registered = world.resource::<RegisteredTypes>();
+/// assert!(registered.is_registered::<Foo>());
+/// assert!(registered.is_registered::<Arc<Foo>>());
+/// assert!(registered.is_registered::<Box<Foo>>());
+/// assert!(registered.is_registered::<PhantomData<Foo>>());
+/// assert!(registered.is_registered::<[Foo; 4]>());
+/// assert_eq!(world.resource::<Count>().0, 1);
+/// ```
 pub trait RegisterInWorld: 'static {
     /// Register type to the world.
     /// 
@@ -100,22 +351,576 @@ pub trait RegisterInWorld: 'static {
     /// which registers components during `on_add` hook, it was decided to use 
     /// [`DeferredWorld`] directly as an argument. You can still use [`DeferredWorld::commands`].
     /// Calling [`World::register`] will immediately flush commands after call to `register`.
+    ///
+    /// When a component is spawned or inserted through [`Commands`](bevy_ecs::system::Commands),
+    /// the commands queued by `register` are applied right after the command that added the component,
+    /// before the next command of the same batch. Bevy flushes the world's command queue after each applied command,
+    /// so the spawner's subsequent commands always observe the registration side effects,
+    /// including the ones touching the same entity.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::component::ComponentId;
+    /// # use bevy_ecs::world::{CommandQueue, DeferredWorld};
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// #[derive(Resource)]
+    /// struct Config;
+    ///
+    /// #[derive(Component)]
+    /// struct Configured;
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct RegistrationCommandsOrder;
+    ///
+    /// impl RegisterInWorld for RegistrationCommandsOrder {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.commands().insert_resource(Config);
+    ///     }
+    ///
+    ///     fn register_with_context(mut world: DeferredWorld, entity: Entity, _id: ComponentId) {
+    ///         world.commands().entity(entity).insert(Configured);
+    ///         Self::register(world);
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<RegisteredTypes>();
+    ///
+    /// let mut queue = CommandQueue::default();
+    /// let mut commands = Commands::new(&mut queue, &world);
+    /// let entity = commands.spawn(RegistrationCommandsOrder).id();
+    /// commands.add(|world: &mut World| assert!(world.contains_resource::<Config>()));
+    /// // Applied after `Configured` was inserted by the registration
+    /// commands.entity(entity).remove::<Configured>();
+    /// queue.apply(&mut world);
+    ///
+    /// assert!(!world.entity(entity).contains::<Configured>());
+    /// ```
     fn register(world: DeferredWorld);
+
+    /// Register type to the world, when registration was triggered by adding component `id` 
+    /// to the `entity`, for example by [`register_on_add`](component::register_on_add).
+    ///
+    /// By default calls [`register`](RegisterInWorld::register).
+    /// Override it to make decisions based on the triggering entity, for example
+    /// by reading its `Parent` component from `bevy_hierarchy` with [`World::get`].
+    ///
+    #[cfg_attr(feature = "bevy_hierarchy", doc = "```")]
+    #[cfg_attr(not(feature = "bevy_hierarchy"), doc = "```ignore")]
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::component::ComponentId;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_hierarchy::{BuildWorldChildren, Parent};
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct SceneRoot;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct UnderSceneRoot(bool);
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(_world: DeferredWorld) {}
+    ///
+    ///     fn register_with_context(mut world: DeferredWorld, entity: Entity, _id: ComponentId) {
+    ///         let under_root = world
+    ///             .get::<Parent>(entity)
+    ///             .is_some_and(|parent| world.get::<SceneRoot>(parent.get()).is_some());
+    ///         world.resource_mut::<UnderSceneRoot>().0 = under_root;
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_resource::<UnderSceneRoot>();
+    ///
+    /// app.world_mut().spawn(SceneRoot).with_children(|root| {
+    ///     root.spawn(Foo);
+    /// });
+    /// assert!(app.world().resource::<UnderSceneRoot>().0);
+    /// ```
+    #[allow(unused_variables)]
+    fn register_with_context(world: DeferredWorld, entity: Entity, id: ComponentId) {
+        Self::register(world);
+    }
+
+    /// Undoes the effects of [`register`](RegisterInWorld::register), called by
+    /// [`RegisterExtension::unregister_all`]. Does nothing by default.
+    ///
+    /// Systems can't be removed from schedules in the targeted bevy version,
+    /// so systems added during registration keep running. Make them depend on something
+    /// removed here, for example with a run condition on a resource.
+    #[allow(unused_variables)]
+    fn unregister(world: DeferredWorld) {}
+
+    /// Whether registrations of this type are deduplicated. `true` by default.
+    ///
+    /// When `false`, the type is still marked as registered in [`RegisteredTypes`],
+    /// but [`register`](RegisterInWorld::register) runs on every registration,
+    /// and for [`ComponentAutoRegister`](component::ComponentAutoRegister) types every time the component is added.
+    /// Be careful: systems and observers requested in `register` are added again every time,
+    /// so `register` should only do things that are fine to repeat, like inserting resources.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{assert_registered, prelude::*};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Count(u32);
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Count>().0 += 1;
+    ///     }
+    ///
+    ///     fn dedup() -> bool {
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Count>();
+    ///
+    /// world.register::<Foo>();
+    /// world.spawn(Foo);
+    /// world.spawn(Foo);
+    /// assert_eq!(world.resource::<Count>().0, 3);
+    /// assert_registered!(world, Foo);
+    /// ```
+    #[inline]
+    fn dedup() -> bool {
+        true
+    }
+
+    /// When [`register`](RegisterInWorld::register) runs. [`RegistrationStage::Immediate`] by default.
+    ///
+    /// Applies to [`RegisterExtension::register`] and to additions of
+    /// [`ComponentAutoRegister`](component::ComponentAutoRegister) components.
+    /// Deferred types are marked as registered right away, so [deduplicated](RegisterInWorld::dedup) types are queued
+    /// only once, while the other ones are queued on every registration, and `register` runs once per queued registration.
+    /// Queued registrations run during the next [`AddingSystems`](add_systems::AddingSystems), before the requested systems are added.
+    /// Deferred registrations triggered by components call `register`, not
+    /// [`register_with_context`](RegisterInWorld::register_with_context), since the entity may be gone by then.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{add_systems::AddingSystems, prelude::*, RegistrationStage};
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Decoration;
+    ///
+    /// impl RegisterInWorld for Decoration {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems(Update, || {});
+    ///     }
+    ///
+    ///     fn stage() -> RegistrationStage {
+    ///         RegistrationStage::Deferred
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(RegisterInWorldPlugin::default());
+    /// app.init_schedule(Update);
+    ///
+    /// let world = app.world_mut();
+    /// world.spawn(Decoration);
+    /// world.spawn(Decoration);
+    /// assert!(world.is_registered::<Decoration>());
+    /// assert_eq!(world.resource::<Schedules>().get(Update).unwrap().systems_len(), 0);
+    ///
+    /// world.run_schedule(AddingSystems);
+    /// assert_eq!(world.resource::<Schedules>().get(Update).unwrap().systems_len(), 1);
+    /// ```
+    #[inline]
+    fn stage() -> RegistrationStage {
+        RegistrationStage::Immediate
+    }
+
+    /// Whether a [deferred](RegistrationStage::Deferred) registration of this type can run. `true` by default.
+    ///
+    /// Checked on every run of [`apply_deferred_registrations`](deferred::apply_deferred_registrations),
+    /// the registration stays queued while it's `false`. Not checked for [`RegistrationStage::Immediate`] types.
+    /// See [`DeferredRegistrations`](deferred::DeferredRegistrations) for bounding the amount of attempts.
+    #[inline]
+    #[allow(unused_variables)]
+    fn ready(world: &World) -> bool {
+        true
+    }
+}
+
+/// When [`RegisterInWorld::register`] runs, see [`RegisterInWorld::stage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RegistrationStage {
+    /// Right when the type is registered.
+    #[default]
+    Immediate,
+    /// During the next [`AddingSystems`](add_systems::AddingSystems), through [`DeferredRegistrations`](deferred::DeferredRegistrations).
+    Deferred,
 }
 
-type TypeIdSet = HashSet<TypeId, NoOpHash>;
+/// Types that can be registered to the world with a key, once for every key.
+/// See [`RegisterExtension::register_keyed`].
+pub trait RegisterKeyedInWorld<K>: 'static {
+    /// Register type to the world for the `key`.
+    fn register(world: DeferredWorld, key: &K);
+}
+
+/// Types that are registered to the world with a configuration value, the first time they are registered.
+/// See [`RegisterExtension::register_with_value`].
+pub trait RegisterInWorldValued: 'static {
+    /// Value that is passed to [`register_valued`](RegisterInWorldValued::register_valued).
+    type Config;
+
+    /// Register type to the world, using the `config`.
+    fn register_valued(world: DeferredWorld, config: Self::Config);
+}
+
+macro_rules! register_wrapper {
+    ($($wrapper:ident),*) => {
+        $(
+            impl<T: RegisterInWorld> RegisterInWorld for $wrapper<T> {
+                fn register(mut world: DeferredWorld) {
+                    world.register::<T>();
+                }
+            }
+        )*
+    };
+}
 
-/// Stores a `HashSet` of types that were registered into the world using [`RegisterInWorld`] trait.
+register_wrapper!(Arc, Box, PhantomData);
+
+impl<T: RegisterInWorld, const N: usize> RegisterInWorld for [T; N] {
+    fn register(mut world: DeferredWorld) {
+        world.register::<T>();
+    }
+}
+
+pub(crate) type TypeIdMap<V> = HashMap<TypeId, V, NoOpHash>;
+
+/// Registrations made into the world using [`RegisterInWorld`] trait.
+///
+/// Maps the [`TypeId`] of every registered type to its name. Alongside it, stores the keys of labeled,
+/// keyed and named registrations, and, for every type, the [`RegistrationOrigin`], the registration tick,
+/// the functions that replay and [unregister](RegisterInWorld::unregister) it,
+/// the [registration order](RegisteredTypes::registration_order)
+/// and the [stack](RegisteredTypes::registration_stack) of the registrations that are currently running.
+///
+/// [`RegisterExtension::register`] and the hooks of [`ComponentAutoRegister`](component::ComponentAutoRegister)
+/// components only mutably borrow this resource when the type is registered for the first time,
+/// so change detection of the resource is only triggered by new registrations.
+///
+/// Like any resource, it's only accessible through Bevy's resource access rules, so it can't be read
+/// from another thread while systems may mutate it. To inspect registrations off-thread, hand out
+/// an owned copy, for example [`to_type_ids`](RegisteredTypes::to_type_ids) or [`snapshot`](RegisteredTypes::snapshot).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::RunSystemOnce;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.register::<Foo>();
+/// assert!(world.is_resource_changed::<RegisteredTypes>());
+///
+/// world.clear_trackers();
+/// world.register::<Foo>();
+/// world.run_system_once(|mut world: DeferredWorld| world.register::<Foo>());
+/// world.spawn(Foo);
+/// assert!(!world.is_resource_changed::<RegisteredTypes>());
+/// ```
 #[derive(Resource, Default)]
 pub struct RegisteredTypes {
-    types: TypeIdSet,
+    types: TypeIdMap<&'static str>,
+    labeled: KeySet,
+    keyed: KeySet,
+    named: HashSet<Cow<'static, str>>,
+    origins: TypeIdMap<RegistrationOrigin>,
+    ticks: TypeIdMap<Tick>,
+    replays: TypeIdMap<fn(&mut World)>,
+    unregisters: TypeIdMap<Unregister>,
+    order: Vec<TypeId>,
+    stack: RegistrationStack,
+}
+
+/// Copy of the state of [`RegisteredTypes`], created by [`RegisteredTypes::snapshot`].
+#[derive(Debug, Clone, Default)]
+pub struct RegisteredTypesSnapshot {
+    types: TypeIdMap<&'static str>,
+    labeled: KeySet,
+    keyed: KeySet,
+    named: HashSet<Cow<'static, str>>,
+    origins: TypeIdMap<RegistrationOrigin>,
+    ticks: TypeIdMap<Tick>,
+    replays: TypeIdMap<fn(&mut World)>,
+    unregisters: TypeIdMap<Unregister>,
+    order: Vec<TypeId>,
+}
+
+impl RegisteredTypesSnapshot {
+    /// Returns the amount of registered types in the snapshot.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns `true` if no types are registered in the snapshot.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+}
+
+/// What caused a registration of a type.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes, RegistrationOrigin};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// struct Bar;
+///
+/// impl RegisterInWorld for Bar {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// struct Baz;
+///
+/// impl RegisterInWorld for Baz {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// let entity = world.spawn(Foo).id();
+/// world.register::<Bar>();
+/// world.register_with_label::<Baz>("debug");
+///
+/// let registered = world.resource::<RegisteredTypes>();
+/// assert_eq!(
+///     registered.origin::<Foo>(),
+///     Some(RegistrationOrigin::Component {
+///         entity,
+///         component: world.component_id::<Foo>().unwrap(),
+///         name: None,
+///     }),
+/// );
+/// assert_eq!(registered.origin::<Bar>(), Some(RegistrationOrigin::Manual));
+/// // Labeled registrations don't register `Baz` itself
+/// assert!(!registered.is_registered::<Baz>());
+/// assert_eq!(registered.origin::<Baz>(), None);
+/// ```
+///
+/// With the `bevy_core` feature, the name of the entity is recorded too:
+///
+#[cfg_attr(feature = "bevy_core", doc = "```")]
+#[cfg_attr(not(feature = "bevy_core"), doc = "```ignore")]
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_core::Name;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes, RegistrationOrigin};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// let entity = world.spawn((Name::new("Player"), Foo)).id();
+///
+/// assert_eq!(
+///     world.resource::<RegisteredTypes>().origin::<Foo>(),
+///     Some(RegistrationOrigin::Component {
+///         entity,
+///         component: world.component_id::<Foo>().unwrap(),
+///         name: Some("Player".to_owned()),
+///     }),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistrationOrigin {
+    /// Registered with [`RegisterExtension`] methods.
+    Manual,
+    /// Registered because the `component` was added to the `entity`,
+    /// for example by [`register_on_add`](component::register_on_add).
+    Component {
+        /// The entity the component was added to.
+        entity: Entity,
+        /// The added component.
+        component: ComponentId,
+        /// The `Name` of the entity when the component was added, if it had one.
+        /// Only recorded with the `bevy_core` feature, `None` otherwise.
+        name: Option<String>,
+    },
+}
+
+/// Registers every type that is registered in `registrations`, but not in the `main_world`,
+/// into the `main_world`, running [`RegisterInWorld::register`] for them.
+///
+/// Use it when entities are built in another world, and then moved into the `main_world`
+/// in a way that doesn't trigger `on_add` hooks. Only types registered with
+/// [`RegisteredTypes::register`], which is used by [`RegisterExtension::register`] and by the hooks,
+/// can be replayed. Labeled registrations and registrations made with [`RegisterExtension::register_as`]
+/// or [`RegisteredTypes::register_id`] are skipped.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, replay_registrations, RegisteredTypes};
+///
+/// #[derive(Resource, Default)]
+/// struct Count(u32);
+///
+/// #[derive(ComponentAutoRegister, Clone, Copy)]
+/// struct Chunk;
+///
+/// impl RegisterInWorld for Chunk {
+///     fn register(mut world: DeferredWorld) {
+///         if let Some(mut count) = world.get_resource_mut::<Count>() {
+///             count.0 += 1;
+///         }
+///     }
+/// }
+///
+/// let mut main_world = World::new();
+/// main_world.init_resource::<Count>();
+/// main_world.init_resource::<RegisteredTypes>();
+///
+/// // Worker
+/// let mut scratch = World::new();
+/// scratch.init_resource::<RegisteredTypes>();
+/// scratch.spawn(Chunk);
+///
+/// // Moving without triggering hooks
+/// let scratch_registrations = scratch.remove_resource::<RegisteredTypes>().unwrap();
+/// assert_eq!(
+///     scratch_registrations.difference(main_world.resource::<RegisteredTypes>()),
+///     [std::any::TypeId::of::<Chunk>()],
+/// );
+///
+/// replay_registrations(&mut main_world, &scratch_registrations);
+/// replay_registrations(&mut main_world, &scratch_registrations);
+/// assert!(main_world.is_registered::<Chunk>());
+/// assert_eq!(main_world.resource::<Count>().0, 1);
+/// ```
+pub fn replay_registrations(main_world: &mut World, registrations: &RegisteredTypes) {
+    let replays: Vec<_> = match main_world.get_resource::<RegisteredTypes>() {
+        Some(registered) => registrations
+            .difference(registered)
+            .iter()
+            .filter_map(|id| registrations.replays.get(id).copied())
+            .collect(),
+        None => registrations.replays.values().copied().collect(),
+    };
+
+    for replay in replays {
+        replay(main_world);
+    }
+}
+
+/// Keys of labeled and keyed registrations and of systems added once, grouped by the owner they are registered for.
+///
+/// Keys are bucketed by their hash, but compared with [`Eq`], so keys with colliding hashes are still distinct.
+#[derive(Default, Clone)]
+pub(crate) struct KeySet {
+    buckets: HashMap<(TypeId, u64), Vec<Arc<dyn DynEq + Send + Sync>>>,
+}
+
+impl KeySet {
+    fn bucket<K: Hash + 'static>(owner: TypeId, key: &K) -> (TypeId, u64) {
+        (owner, FixedState.hash_one((TypeId::of::<K>(), key)))
+    }
+
+    /// Returns `true` if the `key` is in the set for the `owner`.
+    pub(crate) fn contains<K: Hash + Eq + 'static>(&self, owner: TypeId, key: &K) -> bool {
+        self.buckets
+            .get(&Self::bucket(owner, key))
+            .is_some_and(|keys| keys.iter().any(|other| other.as_any().downcast_ref::<K>() == Some(key)))
+    }
+
+    /// Inserts the `key` for the `owner`. Returns the stored key if it wasn't in the set yet.
+    pub(crate) fn insert<K: Hash + Eq + Send + Sync + 'static>(&mut self, owner: TypeId, key: K) -> Option<Arc<K>> {
+        let keys = self.buckets.entry(Self::bucket(owner, &key)).or_default();
+        if keys.iter().any(|other| other.as_any().downcast_ref::<K>() == Some(&key)) {
+            return None;
+        }
+        let key = Arc::new(key);
+        keys.push(key.clone());
+        Some(key)
+    }
+
+    /// Inserts every key of `other`.
+    fn extend(&mut self, other: &KeySet) {
+        for (bucket, other_keys) in &other.buckets {
+            let keys = self.buckets.entry(*bucket).or_default();
+            for key in other_keys {
+                if !keys.iter().any(|existing| existing.dyn_eq(&**key)) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.buckets.shrink_to_fit();
+    }
+}
+
+impl std::fmt::Debug for KeySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeySet")
+            .field("len", &self.buckets.values().map(Vec::len).sum::<usize>())
+            .finish()
+    }
+}
+
+/// Initializes the resources that registrations through [`World`] rely on, together with the resources
+/// of the enabled features that are updated during registrations, like [`RegistrationPlugin`](app::RegistrationPlugin) does.
+#[cfg(any(feature = "bevy_app", feature = "test-utils"))]
+pub(crate) fn init_plugin_registration_resources(world: &mut World) {
+    init_registration_resources(world);
+    #[cfg(feature = "hit-counts")]
+    world.init_resource::<component::RegistrationHitCounts>();
+    #[cfg(feature = "registration-stats")]
+    world.init_resource::<component::RegistrationStats>();
+    #[cfg(feature = "registration-graph")]
+    world.init_resource::<graph::RegistrationGraph>();
 }
 
 impl RegisteredTypes {
     /// Returns wether the type is registered or not.
     #[inline]
     pub fn is_registered<T: RegisterInWorld>(&self) -> bool {
-        self.types.contains(&TypeId::of::<T>())
+        self.types.contains_key(&TypeId::of::<T>())
     }
 
     /// If type should be registered, returns `true`.
@@ -123,34 +928,1364 @@ impl RegisteredTypes {
     /// If type was already registered, returns `false`.
     #[inline]
     pub fn register<T: RegisterInWorld>(&mut self) -> bool {
-        self.types.insert(TypeId::of::<T>())
+        let new = self
+            .types
+            .try_insert(TypeId::of::<T>(), type_name::<T>())
+            .is_ok();
+        if new {
+            self.replays
+                .insert(TypeId::of::<T>(), |world| world.register::<T>());
+            self.unregisters.insert(TypeId::of::<T>(), T::unregister);
+        }
+        new
+    }
+
+    /// Same as [`register`](RegisteredTypes::register), but returns an error if the type was already registered.
+    #[inline]
+    pub fn register_checked<T: RegisterInWorld>(&mut self) -> Result<(), AlreadyRegistered> {
+        if self.register::<T>() {
+            Ok(())
+        } else {
+            Err(AlreadyRegistered::of::<T>())
+        }
+    }
+
+    /// Returns wether the `id` is registered or not. Unlike [`is_registered`](RegisteredTypes::is_registered),
+    /// also sees registrations made by [`register_id`](RegisteredTypes::register_id)
+    /// and [`RegisterExtension::register_as`].
+    #[inline]
+    pub fn is_registered_id(&self, id: TypeId) -> bool {
+        self.types.contains_key(&id)
+    }
+
+    /// If `id` should be registered, returns `true`.
+    ///
+    /// If `id` was already registered, returns `false`.
+    /// The `id` doesn't need to be a [`TypeId`] of a [`RegisterInWorld`] type.
+    /// Its name in [`type_names`](RegisteredTypes::type_names) is `"<unnamed>"`.
+    #[inline]
+    pub fn register_id(&mut self, id: TypeId) -> bool {
+        self.register_id_with_name(id, "<unnamed>")
+    }
+
+    pub(crate) fn register_id_with_name(&mut self, id: TypeId, name: &'static str) -> bool {
+        self.types.try_insert(id, name).is_ok()
+    }
+
+    /// Forgets a registration of the `id` whose `register` never ran, so it can be registered again.
+    pub(crate) fn unmark_id(&mut self, id: TypeId) {
+        self.types.remove(&id);
+        self.replays.remove(&id);
+        self.unregisters.remove(&id);
+    }
+
+    /// Returns the amount of registered types.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns `true` if no types are registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Returns what caused the last registration of the type,
+    /// or `None` if the type wasn't registered through [`RegisterExtension`] or [`ComponentAutoRegister`](component::ComponentAutoRegister).
+    /// Labeled, keyed and [`register_as`](RegisterExtension::register_as) registrations are not recorded.
+    #[inline]
+    pub fn origin<T: RegisterInWorld>(&self) -> Option<RegistrationOrigin> {
+        self.origins.get(&TypeId::of::<T>()).cloned()
+    }
+
+    /// Returns the change tick at which the last registration of the type finished,
+    /// or `None` if the type wasn't registered through [`RegisterExtension`] or [`ComponentAutoRegister`](component::ComponentAutoRegister).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.register_with_label::<Foo>("debug");
+    /// assert_eq!(world.resource::<RegisteredTypes>().registration_tick::<Foo>(), None);
+    ///
+    /// world.increment_change_tick();
+    /// let tick = world.read_change_tick();
+    /// world.register::<Foo>();
+    /// assert_eq!(world.resource::<RegisteredTypes>().registration_tick::<Foo>(), Some(tick));
+    /// ```
+    #[inline]
+    pub fn registration_tick<T: RegisterInWorld>(&self) -> Option<Tick> {
+        self.ticks.get(&TypeId::of::<T>()).copied()
+    }
+
+    /// Returns the amount of types that can be registered without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.types.capacity()
+    }
+
+    /// Shrinks the capacity of the underlying storage as much as possible, to reclaim memory
+    /// after many registrations were removed, for example by [`restore`](RegisteredTypes::restore).
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// use bevy_register_in_world::RegisteredTypes;
+    ///
+    /// let mut registered = RegisteredTypes::default();
+    /// let empty = registered.snapshot();
+    ///
+    /// macro_rules! register_ids {
+    ///     ($($ty:ty),*) => { $(registered.register_id(TypeId::of::<$ty>());)* };
+    /// }
+    /// register_ids!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, bool, char, String);
+    ///
+    /// assert!(registered.capacity() >= 15);
+    ///
+    /// registered.restore(&empty);
+    /// registered.shrink_to_fit();
+    /// assert!(registered.is_empty());
+    /// assert_eq!(registered.capacity(), 0);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        self.labeled.shrink_to_fit();
+        self.keyed.shrink_to_fit();
+        self.named.shrink_to_fit();
+        self.origins.shrink_to_fit();
+        self.ticks.shrink_to_fit();
+        self.replays.shrink_to_fit();
+        self.unregisters.shrink_to_fit();
+        self.order.shrink_to_fit();
+    }
+
+    /// Returns names of the registered types, in no particular order.
+    pub fn type_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.types.values().copied()
+    }
+
+    /// Returns [`TypeId`]s of the registered types, in no particular order.
+    /// The returned `Vec` is owned, so it can be sent to another thread for inspection.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::any::TypeId;
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.register::<Bar>();
+    ///     }
+    /// }
+    ///
+    /// struct Bar;
+    ///
+    /// impl RegisterInWorld for Bar {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.register::<Foo>();
+    ///
+    /// let ids = world.resource::<RegisteredTypes>().to_type_ids();
+    /// let ids = std::thread::spawn(move || ids).join().unwrap();
+    /// assert_eq!(ids.len(), 2);
+    /// assert!(ids.contains(&TypeId::of::<Foo>()));
+    /// assert!(ids.contains(&TypeId::of::<Bar>()));
+    /// ```
+    pub fn to_type_ids(&self) -> Vec<TypeId> {
+        self.types.keys().copied().collect()
+    }
+
+    /// Returns names of the types whose registrations are currently running, from the outermost one.
+    ///
+    /// In debug builds, registering a type that is in the stack logs a warning naming the types of the cycle,
+    /// since the registration is skipped while the type is not fully set up yet.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use bevy_utils::tracing::Level;
+    /// use bevy_register_in_world::{prelude::*, test_utils::capture_logs, RegisteredTypes};
+    ///
+    /// struct A;
+    ///
+    /// impl RegisterInWorld for A {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.register::<B>();
+    ///     }
+    /// }
+    ///
+    /// struct B;
+    ///
+    /// impl RegisterInWorld for B {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         let stack: Vec<_> = world.resource::<RegisteredTypes>().registration_stack().collect();
+    ///         assert_eq!(stack, [std::any::type_name::<A>(), std::any::type_name::<B>()]);
+    ///         world.register::<A>();
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let logs = capture_logs(Level::WARN, || world.register::<A>());
+    /// assert_eq!(world.resource::<RegisteredTypes>().registration_stack().count(), 0);
+    ///
+    /// let (a, b) = (std::any::type_name::<A>(), std::any::type_name::<B>());
+    /// assert!(logs.iter().any(|log| log.starts_with(&format!("Registration cycle: {a} -> {b} -> {a}"))));
+    /// ```
+    ///
+    /// Entries are removed even if `register` panics and the panic is caught.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::panic::{catch_unwind, AssertUnwindSafe};
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// struct Panicking;
+    ///
+    /// impl RegisterInWorld for Panicking {
+    ///     fn register(_world: DeferredWorld) {
+    ///         panic!("failed to register");
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<RegisteredTypes>();
+    ///
+    /// let result = catch_unwind(AssertUnwindSafe(|| DeferredWorld::from(&mut world).register::<Panicking>()));
+    /// assert!(result.is_err());
+    /// assert_eq!(world.resource::<RegisteredTypes>().registration_stack().count(), 0);
+    /// ```
+    pub fn registration_stack(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.stack
+            .to_vec()
+            .into_iter()
+            .map(|id| self.types.get(&id).copied().unwrap_or("<unnamed>"))
+    }
+
+    /// The [`TypeId`] and the name of the innermost type being registered,
+    /// if the type itself is registered, unlike with labeled registrations.
+    pub(crate) fn current_registration(&self) -> Option<(TypeId, &'static str)> {
+        let id = self.stack.last()?;
+        self.types.get(&id).map(|&name| (id, name))
+    }
+
+    /// Returns names of the registered types in the order their registrations finished in,
+    /// so types registered inside of [`register`](RegisterInWorld::register) of another type come before it.
+    /// Only types registered by running [`RegisterInWorld::register`] are included, without the labeled,
+    /// keyed and [`register_as`](RegisterExtension::register_as) registrations, that don't register the type itself.
+    pub fn registration_order(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.order
+            .iter()
+            .map(|id| self.types.get(id).copied().unwrap_or("<unnamed>"))
+    }
+
+    /// Returns sorted names of the registered types, without the ones registered by [`TypeId`] only.
+    /// Names are stable between runs of the same build, so they can be saved and later passed to
+    /// [`LazyRegistrationApp::preregister_by_names`](crate::app::LazyRegistrationApp::preregister_by_names).
+    pub fn to_stable_names(&self) -> Vec<String> {
+        let mut names: Vec<_> = self
+            .type_names()
+            .filter(|name| *name != "<unnamed>")
+            .map(String::from)
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Marks every type registered in `other` as registered in `self`, including labeled, keyed and named registrations.
+    ///
+    /// This doesn't run [`RegisterInWorld::register`] for any type. Use it when the effects
+    /// of registrations in the other world were moved to this world too.
+    ///
+    /// ```
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{RegisterInWorld, RegisteredTypes};
+    ///
+    /// struct Foo;
+    /// struct Bar;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// impl RegisterInWorld for Bar {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// let mut main = RegisteredTypes::default();
+    /// main.register::<Foo>();
+    ///
+    /// let mut loaded = RegisteredTypes::default();
+    /// loaded.register::<Bar>();
+    ///
+    /// main.merge(&loaded);
+    /// assert!(main.is_registered::<Foo>());
+    /// assert!(main.is_registered::<Bar>());
+    /// assert_eq!(main.len(), 2);
+    /// ```
+    pub fn merge(&mut self, other: &RegisteredTypes) {
+        self.types.extend(other.types.iter().map(|(&id, &name)| (id, name)));
+        self.labeled.extend(&other.labeled);
+        self.keyed.extend(&other.keyed);
+        self.named.extend(other.named.iter().cloned());
+        self.origins.extend(other.origins.iter().map(|(&id, origin)| (id, origin.clone())));
+        self.replays.extend(other.replays.iter().map(|(&id, &replay)| (id, replay)));
+        self.unregisters
+            .extend(other.unregisters.iter().map(|(&id, &unregister)| (id, unregister)));
+        for id in &other.order {
+            if let Entry::Vacant(entry) = self.ticks.entry(*id) {
+                entry.insert(other.ticks[id]);
+                self.order.push(*id);
+            }
+        }
+    }
+
+    /// Returns [`TypeId`]s that are registered in `self`, but not in `other`, in no particular order.
+    pub fn difference(&self, other: &RegisteredTypes) -> Vec<TypeId> {
+        self.types
+            .keys()
+            .filter(|id| !other.types.contains_key(*id))
+            .copied()
+            .collect()
+    }
+
+    /// Copies the registration state, so it can be [restored](RegisteredTypes::restore) later.
+    ///
+    /// Registrations have effects that are not rolled back together with the world, like the added systems.
+    /// When restoring the world to an earlier state, take the snapshot before the rollback and restore it
+    /// after, so the re-simulated `on_add` hooks are deduplicated instead of running `register` again.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Count(u32);
+    ///
+    /// #[derive(ComponentAutoRegister)]
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Count>().0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Count>();
+    /// world.init_resource::<RegisteredTypes>();
+    ///
+    /// // The state saved in the rollback buffer, before `Foo` was registered
+    /// let saved = world.resource::<RegisteredTypes>().snapshot();
+    /// let entity = world.spawn(Foo).id();
+    ///
+    /// // Rollback, that restores `RegisteredTypes` together with the rest of the world
+    /// let snapshot = world.resource::<RegisteredTypes>().snapshot();
+    /// world.despawn(entity);
+    /// world.resource_mut::<RegisteredTypes>().restore(&saved);
+    /// // Registrations made before the rollback are kept
+    /// world.resource_mut::<RegisteredTypes>().restore(&snapshot);
+    ///
+    /// // Re-simulation
+    /// world.spawn(Foo);
+    /// assert_eq!(world.resource::<Count>().0, 1);
+    /// ```
+    pub fn snapshot(&self) -> RegisteredTypesSnapshot {
+        RegisteredTypesSnapshot {
+            types: self.types.clone(),
+            labeled: self.labeled.clone(),
+            keyed: self.keyed.clone(),
+            named: self.named.clone(),
+            origins: self.origins.clone(),
+            ticks: self.ticks.clone(),
+            replays: self.replays.clone(),
+            unregisters: self.unregisters.clone(),
+            order: self.order.clone(),
+        }
+    }
+
+    /// Replaces the registration state with the `snapshot`. See [`snapshot`](RegisteredTypes::snapshot).
+    pub fn restore(&mut self, snapshot: &RegisteredTypesSnapshot) {
+        self.types.clone_from(&snapshot.types);
+        self.labeled.clone_from(&snapshot.labeled);
+        self.keyed.clone_from(&snapshot.keyed);
+        self.named.clone_from(&snapshot.named);
+        self.origins.clone_from(&snapshot.origins);
+        self.ticks.clone_from(&snapshot.ticks);
+        self.replays.clone_from(&snapshot.replays);
+        self.unregisters.clone_from(&snapshot.unregisters);
+        self.order.clone_from(&snapshot.order);
+    }
+
+    /// Returns wether the type is registered under the `label` or not.
+    #[inline]
+    pub fn is_registered_with_label<T: RegisterInWorld>(&self, label: impl Hash + Eq + 'static) -> bool {
+        self.labeled.contains(TypeId::of::<T>(), &label)
+    }
+
+    /// If type should be registered under the `label`, returns `true`.
+    ///
+    /// If type was already registered under the `label`, returns `false`.
+    #[inline]
+    pub fn register_with_label<T: RegisterInWorld>(&mut self, label: impl Hash + Eq + Send + Sync + 'static) -> bool {
+        self.labeled.insert(TypeId::of::<T>(), label).is_some()
+    }
+
+    /// Returns wether the type is registered with the `key` or not.
+    #[inline]
+    pub fn is_registered_keyed<T: 'static, K: Hash + Eq + 'static>(&self, key: &K) -> bool {
+        self.keyed.contains(TypeId::of::<T>(), key)
+    }
+
+    /// If type should be registered with the `key`, returns `true`.
+    ///
+    /// If type was already registered with the `key`, returns `false`.
+    ///
+    /// Keys are compared with [`Eq`], keys with the same hash are still distinct.
+    ///
+    /// ```
+    /// # use std::hash::{Hash, Hasher};
+    /// use bevy_register_in_world::RegisteredTypes;
+    ///
+    /// #[derive(PartialEq, Eq)]
+    /// struct Key(u32);
+    ///
+    /// impl Hash for Key {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// struct Foo;
+    ///
+    /// let mut registered = RegisteredTypes::default();
+    /// assert!(registered.register_keyed::<Foo, _>(Key(1)));
+    /// assert!(registered.register_keyed::<Foo, _>(Key(2)));
+    /// assert!(!registered.register_keyed::<Foo, _>(Key(1)));
+    /// assert!(!registered.is_registered_keyed::<Foo, _>(&Key(3)));
+    /// ```
+    #[inline]
+    pub fn register_keyed<T: 'static, K: Hash + Eq + Send + Sync + 'static>(&mut self, key: K) -> bool {
+        self.keyed.insert(TypeId::of::<T>(), key).is_some()
+    }
+
+    /// Returns wether the [`RegistrationBundle`](registration_bundle::RegistrationBundle)
+    /// with the `name` is registered or not.
+    #[inline]
+    pub fn is_registered_name(&self, name: &str) -> bool {
+        self.named.contains(name)
+    }
+
+    /// If the [`RegistrationBundle`](registration_bundle::RegistrationBundle) with the `name`
+    /// should be registered, returns `true`.
+    ///
+    /// If it was already registered, returns `false`.
+    #[inline]
+    pub fn register_name(&mut self, name: impl Into<Cow<'static, str>>) -> bool {
+        self.named.insert(name.into())
+    }
+}
+
+/// Error of registering a type that must be registered only once, returned by
+/// [`RegisterExtension::register_checked`] and [`RegisteredTypes::register_checked`].
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("`{type_name}` is already registered")]
+pub struct AlreadyRegistered {
+    /// Name of the type.
+    pub type_name: &'static str,
+}
+
+impl AlreadyRegistered {
+    /// Creates the error for the type `T`.
+    #[inline]
+    pub fn of<T: 'static>() -> Self {
+        AlreadyRegistered {
+            type_name: type_name::<T>(),
+        }
     }
 }
 
 /// Trait that is implemented for world and app types for convenience of registering.
+///
+/// [`DeferredWorld`] is a system parameter, so observers can take it next to the [`Trigger`](bevy_ecs::observer::Trigger)
+/// and register types reactively, right when the observer runs.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+///
+/// #[derive(Component)]
+/// struct Ship;
+///
+/// struct ShipSystems;
+///
+/// impl RegisterInWorld for ShipSystems {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<RegisteredTypes>();
+/// world.observe(|_trigger: Trigger<OnAdd, Ship>, mut world: DeferredWorld| {
+///     world.register::<ShipSystems>();
+/// });
+///
+/// world.spawn(Ship);
+/// world.flush();
+/// assert!(world.is_registered::<ShipSystems>());
+/// ```
 pub trait RegisterExtension {
     /// Register the specified type into the world using [`RegisterInWorld`].
     /// Won't register again if type was already registered to the world.
+    ///
+    /// Registrations are logged at the debug level, and so are the systems they request
+    /// once they are added to the schedule.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use bevy_utils::tracing::Level;
+    /// use bevy_register_in_world::{prelude::*, test_utils::{capture_logs, RegistrationTestHarness}};
+    ///
+    /// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+    /// struct MySchedule;
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_systems(MySchedule, (|| {}, || {}));
+    ///     }
+    /// }
+    ///
+    /// let mut harness = RegistrationTestHarness::new();
+    /// harness.world_mut().add_schedule(Schedule::new(MySchedule));
+    ///
+    /// let logs = capture_logs(Level::DEBUG, || {
+    ///     harness.register::<Foo>().flush_registrations();
+    /// });
+    /// assert!(logs.contains(&format!("Registering `{}`", std::any::type_name::<Foo>())));
+    /// assert!(logs.contains(&"Adding 2 systems to MySchedule".to_string()));
+    /// ```
     fn register<T: RegisterInWorld>(&mut self);
+
+    /// Register the specified type into the world using [`RegisterInWorld`], 
+    /// deduplicating by the type and the `label` together.
+    /// Won't register again if type was already registered under the same label.
+    ///
+    /// Labeled registrations are tracked separately from [`register`](RegisterExtension::register),
+    /// so registering with a label doesn't mark the type itself as registered and vice versa.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Count(u32);
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Count>().0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Count>();
+    ///
+    /// world.register_with_label::<Foo>("debug");
+    /// world.register_with_label::<Foo>("release");
+    /// world.register_with_label::<Foo>("debug");
+    /// assert_eq!(world.resource::<Count>().0, 2);
+    ///
+    /// let registered = world.resource::<RegisteredTypes>();
+    /// assert!(registered.is_registered_with_label::<Foo>("debug"));
+    /// assert!(!registered.is_registered::<Foo>());
+    /// ```
+    fn register_with_label<T: RegisterInWorld>(&mut self, label: impl Hash + Eq + Send + Sync + 'static);
+
+    /// Register the type into the world using [`RegisterInWorld`], deduplicating by `as_id`
+    /// instead of the [`TypeId`] of `T`. Several types registered under the same `as_id`
+    /// share one registration slot, so only the first of them runs [`RegisterInWorld::register`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::any::TypeId;
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// trait Shape {}
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Count(u32);
+    ///
+    /// struct Circle;
+    /// struct Square;
+    ///
+    /// impl RegisterInWorld for Circle {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Count>().0 += 1;
+    ///     }
+    /// }
+    ///
+    /// impl RegisterInWorld for Square {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Count>().0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Count>();
+    ///
+    /// let alias = TypeId::of::<dyn Shape>();
+    /// world.register_as::<Circle>(alias);
+    /// world.register_as::<Square>(alias);
+    /// assert_eq!(world.resource::<Count>().0, 1);
+    ///
+    /// let registered = world.resource::<RegisteredTypes>();
+    /// assert!(registered.is_registered_id(alias));
+    /// assert!(!registered.is_registered::<Circle>());
+    /// ```
+    fn register_as<T: RegisterInWorld>(&mut self, as_id: TypeId);
+
+    /// Register the type into the world using [`RegisterKeyedInWorld`], deduplicating by the type
+    /// and the `key` together, so `register` runs once for every key.
+    /// Unlike [`register_with_label`](RegisterExtension::register_with_label), `register` receives the key.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::marker::PhantomData;
+    /// use bevy_register_in_world::{prelude::*, RegisterKeyedInWorld, RegisteredTypes};
+    ///
+    /// #[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+    /// enum Kind {
+    ///     Predicted,
+    ///     Interpolated,
+    /// }
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Registered(Vec<Kind>);
+    ///
+    /// struct Replicated<T>(PhantomData<T>);
+    ///
+    /// impl<T: Send + Sync + 'static> RegisterKeyedInWorld<Kind> for Replicated<T> {
+    ///     fn register(mut world: DeferredWorld, key: &Kind) {
+    ///         world.resource_mut::<Registered>().0.push(*key);
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Registered>();
+    ///
+    /// world.register_keyed::<Replicated<u8>, _>(Kind::Predicted);
+    /// world.register_keyed::<Replicated<u8>, _>(Kind::Interpolated);
+    /// world.register_keyed::<Replicated<u8>, _>(Kind::Predicted);
+    /// assert_eq!(world.resource::<Registered>().0, [Kind::Predicted, Kind::Interpolated]);
+    ///
+    /// let registered = world.resource::<RegisteredTypes>();
+    /// assert!(registered.is_registered_keyed::<Replicated<u8>, _>(&Kind::Interpolated));
+    /// assert!(!registered.is_registered_keyed::<Replicated<u16>, _>(&Kind::Interpolated));
+    /// ```
+    fn register_keyed<T, K>(&mut self, key: K)
+    where
+        T: RegisterKeyedInWorld<K>,
+        K: Hash + Eq + Send + Sync + 'static;
+
+    /// Register the type into the world using [`RegisterInWorldValued`], passing the `config` to it.
+    /// Registrations are deduplicated by the [`TypeId`] of the type, the same as with
+    /// [`register`](RegisterExtension::register), so the `config` is only used the first time,
+    /// and dropped afterwards.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// # use std::any::TypeId;
+    /// use bevy_register_in_world::{prelude::*, RegisterInWorldValued, RegisteredTypes};
+    ///
+    /// #[derive(Resource)]
+    /// struct Terrain {
+    ///     chunk_size: u32,
+    /// }
+    ///
+    /// impl RegisterInWorldValued for Terrain {
+    ///     type Config = u32;
+    ///
+    ///     fn register_valued(mut world: DeferredWorld, chunk_size: u32) {
+    ///         world.commands().insert_resource(Terrain { chunk_size });
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.register_with_value::<Terrain>(16);
+    /// world.register_with_value::<Terrain>(32);
+    ///
+    /// assert_eq!(world.resource::<Terrain>().chunk_size, 16);
+    /// assert!(world.resource::<RegisteredTypes>().is_registered_id(TypeId::of::<Terrain>()));
+    /// ```
+    fn register_with_value<T: RegisterInWorldValued>(&mut self, config: T::Config);
+
+    /// Initializes the type as a resource with [`FromWorld`], if the resource doesn't exist yet,
+    /// and then registers it, so [`register`](RegisterInWorld::register) can use the resource.
+    /// The resource is initialized even if the type was registered before.
+    ///
+    /// Implementation for [`DeferredWorld`] queues both steps, in the same order, as one command.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct Settings {
+    ///     entities: u32,
+    /// }
+    ///
+    /// impl FromWorld for Settings {
+    ///     fn from_world(world: &mut World) -> Self {
+    ///         Settings { entities: world.entities().len() }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct Spawners(u32);
+    ///
+    /// impl RegisterInWorld for Settings {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         let entities = world.resource::<Settings>().entities;
+    ///         world.commands().insert_resource(Spawners(entities));
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.spawn_empty();
+    /// world.spawn_empty();
+    /// world.register_from_world::<Settings>();
+    ///
+    /// assert!(world.is_registered::<Settings>());
+    /// assert_eq!(world.resource::<Settings>().entities, 2);
+    /// assert_eq!(world.resource::<Spawners>().0, 2);
+    /// ```
+    fn register_from_world<T: RegisterInWorld + Resource + FromWorld>(&mut self);
+
+    /// Returns wether the type is registered or not. 
+    /// Returns `false` if [`RegisteredTypes`] doesn't exist.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::SubApp;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(world: DeferredWorld) {
+    ///         assert!(world.is_registered::<Foo>());
+    ///     }
+    /// }
+    ///
+    /// // Without `RegisteredTypes`
+    /// let mut world = World::new();
+    /// assert!(!world.is_registered::<Foo>());
+    /// assert!(!DeferredWorld::from(&mut world).is_registered::<Foo>());
+    /// assert!(!SubApp::new().is_registered::<Foo>());
+    ///
+    /// // Without `RegisterInWorldPlugin`
+    /// let mut app = App::new();
+    /// assert!(!app.is_registered::<Foo>());
+    ///
+    /// app.register::<Foo>();
+    /// assert!(app.is_registered::<Foo>());
+    /// assert!(app.main().is_registered::<Foo>());
+    /// assert!(app.world().is_registered::<Foo>());
+    /// ```
+    fn is_registered<T: RegisterInWorld>(&self) -> bool;
+
+    /// Same as [`register`](RegisterExtension::register), but returns an error instead of
+    /// doing nothing if the type was already registered. Use it for types that must be registered only once,
+    /// when registering them again is a bug.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{prelude::*, AlreadyRegistered, RegisteredTypes};
+    ///
+    /// struct Owned;
+    ///
+    /// impl RegisterInWorld for Owned {
+    ///     fn register(_world: DeferredWorld) {}
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// assert_eq!(world.register_checked::<Owned>(), Ok(()));
+    /// assert_eq!(world.register_checked::<Owned>(), Err(AlreadyRegistered::of::<Owned>()));
+    ///
+    /// let mut registered = RegisteredTypes::default();
+    /// assert!(registered.register_checked::<Owned>().is_ok());
+    /// assert!(registered.register_checked::<Owned>().is_err());
+    /// ```
+    fn register_checked<T: RegisterInWorld>(&mut self) -> Result<(), AlreadyRegistered> {
+        if self.is_registered::<T>() {
+            return Err(AlreadyRegistered::of::<T>());
+        }
+        self.register::<T>();
+        Ok(())
+    }
+
+    /// Returns names of the registered types, sorted. 
+    /// Returns an empty `Vec` if [`RegisteredTypes`] doesn't exist.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(world: DeferredWorld) {
+    ///         assert_eq!(world.registered_type_names(), [std::any::type_name::<Foo>()]);
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.register::<Foo>();
+    /// app.register::<Box<Foo>>();
+    ///
+    /// assert_eq!(
+    ///     app.registered_type_names(),
+    ///     [std::any::type_name::<Box<Foo>>(), std::any::type_name::<Foo>()],
+    /// );
+    /// assert_eq!(app.main().registered_type_names().len(), 2);
+    /// assert_eq!(app.world().registered_type_names().len(), 2);
+    /// ```
+    fn registered_type_names(&self) -> Vec<&'static str>;
+
+    /// Tears down the registrations: calls [`RegisterInWorld::unregister`] of every registered type
+    /// in the reverse order of [`RegisteredTypes::registration_order`], so types are unregistered before
+    /// the types they registered, and then clears [`RegisteredTypes`]. If `unregister` of a type panics,
+    /// an error is logged and the rest of the types are still unregistered.
+    ///
+    /// Use it to shut down cleanly in integration tests, or to rebuild the world in an editor.
+    /// Only types registered by running [`RegisterInWorld::register`] are unregistered.
+    /// Systems added during registration are not removed: bevy 0.14 can't remove systems from schedules,
+    /// so they stay and keep running, see [`RegisterInWorld::unregister`]. Registering a type again
+    /// runs its `register` again, so the systems it adds are added once more.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{prelude::*, RegisteredTypes};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// struct Assets;
+    ///
+    /// impl RegisterInWorld for Assets {
+    ///     fn register(_world: DeferredWorld) {}
+    ///
+    ///     fn unregister(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Log>().0.push("assets");
+    ///     }
+    /// }
+    ///
+    /// struct Renderer;
+    ///
+    /// impl RegisterInWorld for Renderer {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.register::<Assets>();
+    ///     }
+    ///
+    ///     fn unregister(_world: DeferredWorld) {
+    ///         panic!("Failed to release the device");
+    ///     }
+    /// }
+    ///
+    /// struct Sprites;
+    ///
+    /// impl RegisterInWorld for Sprites {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.register::<Renderer>();
+    ///         world.register::<Assets>();
+    ///     }
+    ///
+    ///     fn unregister(mut world: DeferredWorld) {
+    ///         world.resource_mut::<Log>().0.push("sprites");
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Log>();
+    /// world.register::<Sprites>();
+    /// assert_eq!(
+    ///     world.resource::<RegisteredTypes>().registration_order().collect::<Vec<_>>(),
+    ///     [std::any::type_name::<Assets>(), std::any::type_name::<Renderer>(), std::any::type_name::<Sprites>()],
+    /// );
+    ///
+    /// // `Renderer` panics, but `Assets` is still unregistered
+    /// world.unregister_all();
+    /// assert_eq!(world.resource::<Log>().0, ["sprites", "assets"]);
+    /// assert!(world.resource::<RegisteredTypes>().is_empty());
+    /// assert!(!world.is_registered::<Sprites>());
+    /// ```
+    fn unregister_all(&mut self);
+}
+
+/// Marks the type as registered in [`RegisteredTypes`]. Returns `true` if it wasn't registered before,
+/// or if the type opted out of deduplication with [`RegisterInWorld::dedup`].
+///
+/// Takes the resource mutably only when needed, to not trigger change detection on deduplication.
+/// Checks [`RegistrationGuard`] before marking, so a caught panic of a strict guard leaves the type unregistered.
+pub(crate) fn mark_registered<T: RegisterInWorld>(world: &mut DeferredWorld) -> bool {
+    let registered = world.resource::<RegisteredTypes>().is_registered::<T>();
+    if registered && T::dedup() {
+        #[cfg(debug_assertions)]
+        warn_on_cycle::<T>(world.resource::<RegisteredTypes>());
+        return false;
+    }
+    check_registration_guard::<T>(world);
+    if !registered {
+        world.resource_mut::<RegisteredTypes>().register::<T>();
+    }
+    true
+}
+
+/// Panics or logs a warning if a [`RegistrationGuard`] window is open, see [`RegistrationGuard`].
+fn check_registration_guard<T: 'static>(world: &DeferredWorld) {
+    let Some(guard) = world.get_resource::<RegistrationGuard>() else {
+        return;
+    };
+    if guard.is_in_window() {
+        if guard.is_strict() {
+            panic!("`{}` was registered inside a no-registration window", type_name::<T>());
+        }
+        warn!("`{}` was registered inside a no-registration window", type_name::<T>());
+    }
+}
+
+/// Logs a warning if `T` is in the registration stack, naming the types of the cycle.
+#[cfg(debug_assertions)]
+fn warn_on_cycle<T: 'static>(registered: &RegisteredTypes) {
+    let id = TypeId::of::<T>();
+    let stack = registered.stack.to_vec();
+    let Some(start) = stack.iter().position(|&entry| entry == id) else {
+        return;
+    };
+    let cycle: Vec<_> = stack[start..]
+        .iter()
+        .chain([&id])
+        .map(|id| registered.types.get(id).copied().unwrap_or("<unnamed>"))
+        .collect();
+    warn!(
+        "Registration cycle: {}. `{}` is used before its registration finished",
+        cycle.join(" -> "),
+        type_name::<T>(),
+    );
+}
+
+/// Same as [`mark_registered`], but for valued registrations, which are always deduplicated.
+pub(crate) fn mark_registered_valued<T: RegisterInWorldValued>(world: &mut DeferredWorld) -> bool {
+    if world
+        .resource::<RegisteredTypes>()
+        .is_registered_id(TypeId::of::<T>())
+    {
+        return false;
+    }
+    check_registration_guard::<T>(world);
+    world
+        .resource_mut::<RegisteredTypes>()
+        .register_id_with_name(TypeId::of::<T>(), type_name::<T>())
+}
+
+/// Same as [`mark_registered`], but for labeled registrations.
+pub(crate) fn mark_registered_with_label<T: RegisterInWorld>(
+    world: &mut DeferredWorld,
+    label: impl Hash + Eq + Send + Sync + 'static,
+) -> bool {
+    let registered = world.resource::<RegisteredTypes>().labeled.contains(TypeId::of::<T>(), &label);
+    if registered && T::dedup() {
+        return false;
+    }
+    check_registration_guard::<T>(world);
+    if !registered {
+        world.resource_mut::<RegisteredTypes>().register_with_label::<T>(label);
+    }
+    true
+}
+
+/// Same as [`mark_registered`], but for registrations under another id.
+pub(crate) fn mark_registered_as<T: RegisterInWorld>(world: &mut DeferredWorld, as_id: TypeId) -> bool {
+    let registered = world.resource::<RegisteredTypes>().is_registered_id(as_id);
+    if registered && T::dedup() {
+        return false;
+    }
+    check_registration_guard::<T>(world);
+    if !registered {
+        world
+            .resource_mut::<RegisteredTypes>()
+            .register_id_with_name(as_id, type_name::<T>());
+    }
+    true
+}
+
+/// Same as [`mark_registered`], but for keyed registrations. Keyed registrations are always deduplicated,
+/// so the stored key is returned only if it wasn't registered yet.
+pub(crate) fn mark_registered_keyed<T: 'static, K: Hash + Eq + Send + Sync + 'static>(
+    world: &mut DeferredWorld,
+    key: K,
+) -> Option<Arc<K>> {
+    if world.resource::<RegisteredTypes>().is_registered_keyed::<T, K>(&key) {
+        return None;
+    }
+    check_registration_guard::<T>(world);
+    world.resource_mut::<RegisteredTypes>().keyed.insert(TypeId::of::<T>(), key)
+}
+
+/// Calls `register` for the type `T`, inside of a tracing span when the `trace` feature is enabled,
+/// measuring it if [`RegistrationTimings`] exists, and then calls [`RegistrationCallbacks`].
+/// Records the `origin` before the registration,
+/// and the tick and the place in the registration order after it.
+pub(crate) fn run_registration<T: 'static>(
+    world: DeferredWorld,
+    origin: RegistrationOrigin,
+    register: impl FnOnce(DeferredWorld),
+) {
+    run_registration_inner::<T>(world, origin, true, register);
+}
+
+/// Same as [`run_registration`], for registrations that don't register `T` itself, like labeled ones,
+/// so nothing is recorded for `T`.
+pub(crate) fn run_untracked_registration<T: 'static>(
+    world: DeferredWorld,
+    origin: RegistrationOrigin,
+    register: impl FnOnce(DeferredWorld),
+) {
+    run_registration_inner::<T>(world, origin, false, register);
+}
+
+fn run_registration_inner<T: 'static>(
+    mut world: DeferredWorld,
+    origin: RegistrationOrigin,
+    tracked: bool,
+    register: impl FnOnce(DeferredWorld),
+) {
+    #[cfg(feature = "trace")]
+    let _span = info_span!("register_in_world", type_name = type_name::<T>()).entered();
+    debug!("Registering `{}`", type_name::<T>());
+
+    let mut registered = world.resource_mut::<RegisteredTypes>();
+    if tracked {
+        registered.origins.insert(TypeId::of::<T>(), origin.clone());
+    }
+    let stack = registered.stack.push(TypeId::of::<T>());
+
+    let start = world
+        .contains_resource::<RegistrationTimings>()
+        .then(Instant::now);
+    let in_progress = world
+        .get_resource::<RegistrationInProgress>()
+        .map(RegistrationInProgress::enter);
+    register(world.reborrow());
+    drop(in_progress);
+    drop(stack);
+    let tick = world.read_change_tick();
+    let mut registered = world.resource_mut::<RegisteredTypes>();
+    if tracked && registered.ticks.insert(TypeId::of::<T>(), tick).is_none() {
+        registered.order.push(TypeId::of::<T>());
+    }
+
+    if let Some(start) = start {
+        let duration = start.elapsed();
+        if let Some(mut timings) = world.get_resource_mut::<RegistrationTimings>() {
+            let timing = RegistrationTiming {
+                type_name: type_name::<T>(),
+                duration,
+                tick,
+            };
+            if timings.record(timing) {
+                warn!("Registering `{}` took {duration:?}", type_name::<T>());
+            }
+        }
+    }
+
+    if let Some(callbacks) = world.get_resource::<RegistrationCallbacks>() {
+        callbacks.call(&RegistrationRecord {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            tick,
+            origin,
+        });
+    }
 }
 
 impl RegisterExtension for DeferredWorld<'_> {
     fn register<T: RegisterInWorld>(&mut self) {
-        let mut initialized = self.resource_mut::<RegisteredTypes>();
+        if mark_registered::<T>(self) && !queue_deferred::<T>(self, RegistrationOrigin::Manual) {
+            run_registration::<T>(self.reborrow(), RegistrationOrigin::Manual, T::register);
+        }
+    }
+
+    fn register_with_label<T: RegisterInWorld>(&mut self, label: impl Hash + Eq + Send + Sync + 'static) {
+        if mark_registered_with_label::<T>(self, label) {
+            run_untracked_registration::<T>(self.reborrow(), RegistrationOrigin::Manual, T::register);
+        }
+    }
+
+    fn register_as<T: RegisterInWorld>(&mut self, as_id: TypeId) {
+        if mark_registered_as::<T>(self, as_id) {
+            run_untracked_registration::<T>(self.reborrow(), RegistrationOrigin::Manual, T::register);
+        }
+    }
+
+    fn register_keyed<T, K>(&mut self, key: K)
+    where
+        T: RegisterKeyedInWorld<K>,
+        K: Hash + Eq + Send + Sync + 'static,
+    {
+        if let Some(key) = mark_registered_keyed::<T, K>(self, key) {
+            run_untracked_registration::<T>(self.reborrow(), RegistrationOrigin::Manual, |world| {
+                T::register(world, &key)
+            });
+        }
+    }
+
+    fn register_with_value<T: RegisterInWorldValued>(&mut self, config: T::Config) {
+        if mark_registered_valued::<T>(self) {
+            run_registration::<T>(self.reborrow(), RegistrationOrigin::Manual, |world| {
+                T::register_valued(world, config)
+            });
+        }
+    }
+
+    fn register_from_world<T: RegisterInWorld + Resource + FromWorld>(&mut self) {
+        self.commands().add(|world: &mut World| {
+            world.init_resource::<T>();
+            world.register::<T>();
+        });
+    }
 
-        if initialized.register::<T>() {
-            T::register(self.reborrow());
+    #[inline]
+    fn is_registered<T: RegisterInWorld>(&self) -> bool {
+        World::is_registered::<T>(self)
+    }
+
+    #[inline]
+    fn registered_type_names(&self) -> Vec<&'static str> {
+        World::registered_type_names(self)
+    }
+
+    /// Commands queued by `unregister` are not flushed.
+    fn unregister_all(&mut self) {
+        for (name, unregister) in unregisters_in_reverse(self.get_resource::<RegisteredTypes>()) {
+            run_unregister(name, || unregister(self.reborrow()));
+        }
+        if let Some(mut registered) = self.get_resource_mut::<RegisteredTypes>() {
+            *registered = RegisteredTypes::default();
         }
     }
 }
 
+/// [`RegisterInWorld::unregister`] of a type.
+type Unregister = fn(DeferredWorld);
+
+/// Names and [`RegisterInWorld::unregister`] of the registered types, in the reverse registration order.
+fn unregisters_in_reverse(registered: Option<&RegisteredTypes>) -> Vec<(&'static str, Unregister)> {
+    let Some(registered) = registered else {
+        return Vec::new();
+    };
+    registered
+        .order
+        .iter()
+        .rev()
+        .filter_map(|id| {
+            let unregister = *registered.unregisters.get(id)?;
+            Some((registered.types.get(id).copied().unwrap_or("<unnamed>"), unregister))
+        })
+        .collect()
+}
+
+/// Runs the `unregister`, logging an error instead of propagating the panic.
+fn run_unregister(name: &'static str, unregister: impl FnOnce()) {
+    debug!("Unregistering `{name}`");
+    if catch_unwind(AssertUnwindSafe(unregister)).is_err() {
+        error!("Unregistering `{name}` panicked, unregistering the rest of the types");
+    }
+}
+
+/// Extension trait to batch several registrations together.
+pub trait RegisterScope {
+    /// Runs `scope` with a [`DeferredWorld`], so all the registrations inside of it
+    /// are done without flushing commands, and flushes the commands exactly once, after `scope` returns.
+    ///
+    /// Commands queued by a registration are applied only after the scope closes, so,
+    /// for example, a resource that one registration inserts through commands
+    /// is not visible to the registrations after it inside the same scope.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::{prelude::*, RegisterScope};
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Config;
+    ///
+    /// struct A;
+    ///
+    /// impl RegisterInWorld for A {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.commands().init_resource::<Config>();
+    ///     }
+    /// }
+    ///
+    /// struct B;
+    ///
+    /// impl RegisterInWorld for B {
+    ///     fn register(world: DeferredWorld) {
+    ///         // `A` was registered in the same scope, but commands are not flushed yet
+    ///         assert!(!world.contains_resource::<Config>());
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.register_scope(|world| {
+    ///     world.register::<A>();
+    ///     world.register::<B>();
+    /// });
+    ///
+    /// assert!(world.contains_resource::<Config>());
+    /// assert!(world.is_registered::<A>() && world.is_registered::<B>());
+    /// ```
+    fn register_scope<R>(&mut self, scope: impl FnOnce(&mut DeferredWorld) -> R) -> R;
+}
+
+impl RegisterScope for World {
+    fn register_scope<R>(&mut self, scope: impl FnOnce(&mut DeferredWorld) -> R) -> R {
+        init_registration_resources(self);
+
+        let mut world: DeferredWorld = self.into();
+        let result = scope(&mut world);
+        self.flush_commands();
+        result
+    }
+}
+
+/// Initializes the resources that registrations through [`World`] rely on, if they don't exist yet.
+fn init_registration_resources(world: &mut World) {
+    if !world.contains_resource::<RegisteredTypes>() {
+        world.init_resource::<RegisteredTypes>();
+    }
+    if !world.contains_resource::<RegistrationInProgress>() {
+        world.init_resource::<RegistrationInProgress>();
+    }
+}
+
 impl RegisterExtension for World {
     fn register<T: RegisterInWorld>(&mut self) {
-        let mut initialized = self.get_resource_or_insert_with::<RegisteredTypes>(Default::default);
+        init_registration_resources(self);
+
+        let mut world: DeferredWorld = self.into();
+        if mark_registered::<T>(&mut world) && !queue_deferred::<T>(&mut world, RegistrationOrigin::Manual) {
+            run_registration::<T>(world, RegistrationOrigin::Manual, T::register);
+            self.flush_commands();
+        }
+    }
+
+    fn register_with_label<T: RegisterInWorld>(&mut self, label: impl Hash + Eq + Send + Sync + 'static) {
+        init_registration_resources(self);
+
+        let mut world: DeferredWorld = self.into();
+        if mark_registered_with_label::<T>(&mut world, label) {
+            run_untracked_registration::<T>(world, RegistrationOrigin::Manual, T::register);
+            self.flush_commands();
+        }
+    }
+
+    fn register_as<T: RegisterInWorld>(&mut self, as_id: TypeId) {
+        init_registration_resources(self);
+
+        let mut world: DeferredWorld = self.into();
+        if mark_registered_as::<T>(&mut world, as_id) {
+            run_untracked_registration::<T>(world, RegistrationOrigin::Manual, T::register);
+            self.flush_commands();
+        }
+    }
+
+    fn register_keyed<T, K>(&mut self, key: K)
+    where
+        T: RegisterKeyedInWorld<K>,
+        K: Hash + Eq + Send + Sync + 'static,
+    {
+        init_registration_resources(self);
+
+        let mut world: DeferredWorld = self.into();
+        if let Some(key) = mark_registered_keyed::<T, K>(&mut world, key) {
+            run_untracked_registration::<T>(world, RegistrationOrigin::Manual, |world| {
+                T::register(world, &key)
+            });
+            self.flush_commands();
+        }
+    }
+
+    fn register_with_value<T: RegisterInWorldValued>(&mut self, config: T::Config) {
+        init_registration_resources(self);
 
-        if initialized.register::<T>() {
-            T::register(self.into());
+        let mut world: DeferredWorld = self.into();
+        if mark_registered_valued::<T>(&mut world) {
+            run_registration::<T>(world, RegistrationOrigin::Manual, |world| {
+                T::register_valued(world, config)
+            });
             self.flush_commands();
         }
     }
+
+    fn register_from_world<T: RegisterInWorld + Resource + FromWorld>(&mut self) {
+        self.init_resource::<T>();
+        self.register::<T>();
+    }
+
+    fn is_registered<T: RegisterInWorld>(&self) -> bool {
+        self.get_resource::<RegisteredTypes>()
+            .is_some_and(|registered| registered.is_registered::<T>())
+    }
+
+    fn registered_type_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = self
+            .get_resource::<RegisteredTypes>()
+            .map(|registered| registered.type_names().collect())
+            .unwrap_or_default();
+        names.sort_unstable();
+        names
+    }
+
+    /// Commands queued by `unregister` are flushed after every type.
+    fn unregister_all(&mut self) {
+        for (name, unregister) in unregisters_in_reverse(self.get_resource::<RegisteredTypes>()) {
+            run_unregister(name, || {
+                unregister(self.into());
+                self.flush_commands();
+            });
+        }
+        if let Some(mut registered) = self.get_resource_mut::<RegisteredTypes>() {
+            *registered = RegisteredTypes::default();
+        }
+    }
 }
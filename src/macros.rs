@@ -0,0 +1,255 @@
+//! Declarative macros
+
+/// Implements [`RegisterInWorld`](crate::RegisterInWorld) for a type, adding the systems to the schedules
+/// during registration.
+///
+/// Every generic parameter of the type gets `Send + Sync + 'static` bounds, and every system
+/// is instantiated with the generic parameters of the type, in the same order.
+/// Systems for different schedules are separated with `;`.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{prelude::*, register_systems, test_utils::*};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct GenericComponent<A, B>(A, B)
+///     where A: Send + Sync + 'static, B: Send + Sync + 'static;
+///
+/// register_systems!(GenericComponent<A, B> => Update: system_a, system_b; Last: system_a);
+///
+/// fn system_a<A, B>(query: Query<&GenericComponent<A, B>>)
+///     where A: Send + Sync + 'static, B: Send + Sync + 'static
+/// {
+///     // do_something ...
+/// }
+///
+/// fn system_b<A, B>(query: Query<&GenericComponent<A, B>>)
+///     where A: Send + Sync + 'static, B: Send + Sync + 'static
+/// {
+///     // do_something ...
+/// }
+///
+/// let mut app = registration_test_app();
+///
+/// app.world_mut().spawn(GenericComponent(0u8, 0u16));
+/// app.update();
+///
+/// assert_registered::<GenericComponent<u8, u16>>(&app);
+/// let schedules = app.world().resource::<Schedules>();
+/// assert_eq!(schedules.get(Update).unwrap().systems_len(), 2);
+/// assert_eq!(schedules.get(Last).unwrap().systems_len(), 1);
+/// ```
+///
+/// Types without generic parameters are supported too, and trailing `,` and `;` are allowed.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{prelude::*, register_systems, test_utils::*};
+///
+/// struct Foo;
+///
+/// register_systems!(Foo =>
+///     PreUpdate: system_a;
+///     Update: system_a, system_b,;
+///     PostUpdate: system_b,;
+/// );
+///
+/// fn system_a() {}
+///
+/// fn system_b() {}
+///
+/// let mut app = registration_test_app();
+///
+/// app.register::<Foo>();
+/// app.update();
+///
+/// assert_registered::<Foo>(&app);
+/// let schedules = app.world().resource::<Schedules>();
+/// assert_eq!(schedules.get(PreUpdate).unwrap().systems_len(), 1);
+/// assert_eq!(schedules.get(Update).unwrap().systems_len(), 2);
+/// assert_eq!(schedules.get(PostUpdate).unwrap().systems_len(), 1);
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_app::prelude::*;
+/// use bevy_register_in_world::register_systems;
+///
+/// struct Foo;
+///
+/// // Missing `=>` between the type and the schedules
+/// register_systems!(Foo Update: system_a);
+///
+/// fn system_a() {}
+/// ```
+#[macro_export]
+macro_rules! register_systems {
+    ($ty:ident $(<$($generic:ident),+ $(,)?>)? => $($schedule:path: $($system:ident),+ $(,)?);+ $(;)?) => {
+        $crate::register_systems!(
+            @impl $ty [$($($generic),+)?] [$($($generic),+)?] => $($schedule: $($system),+);+
+        );
+    };
+    (@impl $ty:ident [$($generic:ident),*] $generics:tt => $($schedule:path: $($system:ident),+);+) => {
+        impl<$($generic: Send + Sync + 'static),*> $crate::RegisterInWorld for $ty<$($generic),*> {
+            fn register(mut world: $crate::__macro_exports::DeferredWorld) {
+                $(
+                    $crate::add_systems::WorldAddSystems::add_systems(
+                        &mut world,
+                        $schedule,
+                        ($($crate::register_systems!(@system $system $generics)),+,),
+                    );
+                )+
+            }
+        }
+    };
+    (@system $system:ident [$($generic:ident),*]) => {
+        $system::<$($generic),*>
+    };
+}
+
+/// Asserts that the type is registered in the world. Takes anything that implements
+/// [`RegisterExtension`](crate::RegisterExtension) (or a reference to it) and the type.
+///
+/// On failure, panics with the names of the types that are registered.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{assert_not_registered, assert_registered, prelude::*};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// assert_not_registered!(world, Foo);
+///
+/// world.register::<Foo>();
+/// assert_registered!(world, Foo);
+/// assert_registered!(&world, Foo, "registered by {}", "hand");
+/// ```
+#[macro_export]
+macro_rules! assert_registered {
+    ($world:expr, $ty:ty $(,)?) => {
+        $crate::assert_registered!(@assert $world, $ty, true, "");
+    };
+    ($world:expr, $ty:ty, $($arg:tt)+) => {
+        $crate::assert_registered!(@assert $world, $ty, true, ": {}", ::core::format_args!($($arg)+));
+    };
+    (@assert $world:expr, $ty:ty, $expected:literal, $($message:tt)+) => {{
+        #[allow(unused_imports)]
+        use $crate::RegisterExtension as _;
+        let world = &$world;
+        if world.is_registered::<$ty>() != $expected {
+            ::core::panic!(
+                "assertion failed: `{}` is {}registered{}\nRegistered types: {:?}",
+                ::core::any::type_name::<$ty>(),
+                if $expected { "not " } else { "" },
+                ::core::format_args!($($message)+),
+                world.registered_type_names(),
+            );
+        }
+    }};
+}
+
+/// Asserts that the type is not registered in the world. The opposite of [`assert_registered!`].
+///
+/// ```should_panic
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{assert_not_registered, prelude::*};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.register::<Foo>();
+/// // Panics, listing the registered types
+/// assert_not_registered!(world, Foo);
+/// ```
+#[macro_export]
+macro_rules! assert_not_registered {
+    ($world:expr, $ty:ty $(,)?) => {
+        $crate::assert_registered!(@assert $world, $ty, false, "");
+    };
+    ($world:expr, $ty:ty, $($arg:tt)+) => {
+        $crate::assert_registered!(@assert $world, $ty, false, ": {}", ::core::format_args!($($arg)+));
+    };
+}
+
+/// Statically asserts that the types implement [`RegisterInWorld`](crate::RegisterInWorld).
+///
+/// ```
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{assert_register_in_world, prelude::*};
+///
+/// struct Foo<T>(T);
+///
+/// impl<T: Send + Sync + 'static> RegisterInWorld for Foo<T> {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// assert_register_in_world!(Foo<i32>, Foo<String>);
+/// ```
+///
+/// ```compile_fail
+/// use bevy_register_in_world::assert_register_in_world;
+///
+/// struct Foo;
+///
+/// assert_register_in_world!(Foo);
+/// ```
+#[macro_export]
+macro_rules! assert_register_in_world {
+    ($($ty:ty),+ $(,)?) => {
+        const _: fn() = || {
+            fn assert<T: $crate::RegisterInWorld>() {}
+            $(assert::<$ty>();)+
+        };
+    };
+}
+
+/// Statically asserts that the types implement [`ComponentAutoRegister`](crate::component::ComponentAutoRegister).
+///
+/// ```
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{assert_component_auto_register, prelude::*};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// assert_component_auto_register!(Foo);
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{assert_component_auto_register, prelude::*};
+///
+/// // Registrable, but not a component
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// assert_component_auto_register!(Foo);
+/// ```
+#[macro_export]
+macro_rules! assert_component_auto_register {
+    ($($ty:ty),+ $(,)?) => {
+        const _: fn() = || {
+            fn assert<T: $crate::component::ComponentAutoRegister>() {}
+            $(assert::<$ty>();)+
+        };
+    };
+}
@@ -0,0 +1,183 @@
+//! Registering types through events
+
+use bevy_consumable_event::ConsumableEvents;
+use bevy_ecs::{
+    event::Event,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::debug;
+use std::any::{type_name, TypeId};
+
+use crate::{RegisterExtension, RegisterInWorld};
+
+/// Registers the types during [`AddingSystems`](crate::add_systems::AddingSystems) schedule,
+/// before the requested systems are added. Types that are already registered are skipped,
+/// so sending the event for the same type several times registers it once.
+/// Should use [`ConsumableEventWriter`](bevy_consumable_event::ConsumableEventWriter) to write events.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_consumable_event::ConsumableEventWriter;
+/// use bevy_register_in_world::prelude::*;
+///
+/// #[derive(Resource, Default)]
+/// struct Registrations(u32);
+///
+/// struct Loot;
+///
+/// impl RegisterInWorld for Loot {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Registrations>().0 += 1;
+///     }
+/// }
+///
+/// struct Chest;
+///
+/// impl RegisterInWorld for Chest {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// fn open_chests(mut writer: ConsumableEventWriter<RegisterTypes>) {
+///     writer.send(RegisterTypes::of::<Loot>().and::<Chest>());
+///     writer.send(RegisterTypes::of::<Loot>());
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(RegisterInWorldPlugin::default());
+/// app.init_resource::<Registrations>();
+/// app.add_systems(Update, open_chests.run_if(run_once()));
+///
+/// app.update();
+/// assert!(app.world().is_registered::<Loot>());
+/// assert!(app.world().is_registered::<Chest>());
+/// assert_eq!(app.world().resource::<Registrations>().0, 1);
+/// ```
+#[derive(Event, Debug, Clone)]
+pub struct RegisterTypes(Vec<ErasedRegistration>);
+
+impl RegisterTypes {
+    /// Creates the event registering `T`.
+    pub fn of<T: RegisterInWorld>() -> Self {
+        RegisterTypes(vec![ErasedRegistration::of::<T>()])
+    }
+
+    /// Adds `T` to the types registered by the event. Types are registered in the order they were added in.
+    pub fn and<T: RegisterInWorld>(mut self) -> Self {
+        self.0.push(ErasedRegistration::of::<T>());
+        self
+    }
+
+    /// Types registered by the event.
+    #[inline]
+    pub fn types(&self) -> &[ErasedRegistration] {
+        &self.0
+    }
+}
+
+/// Type registered by [`RegisterTypes`], with its type erased.
+#[derive(Debug, Clone, Copy)]
+pub struct ErasedRegistration {
+    id: TypeId,
+    name: &'static str,
+    register: fn(DeferredWorld),
+}
+
+impl ErasedRegistration {
+    /// Creates the registration of `T`.
+    pub fn of<T: RegisterInWorld>() -> Self {
+        ErasedRegistration {
+            id: TypeId::of::<T>(),
+            name: type_name::<T>(),
+            register: |mut world| world.register::<T>(),
+        }
+    }
+
+    /// [`TypeId`] of the registered type.
+    #[inline]
+    pub fn id(&self) -> TypeId {
+        self.id
+    }
+
+    /// Name of the registered type.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Consumes all [`RegisterTypes`] events, and registers the types, flushing commands after each one.
+/// This should *only* run during [`AddingSystems`](crate::add_systems::AddingSystems) schedules,
+/// in [`RegisterInWorldSet::ApplyRegisterTypes`](crate::add_systems::RegisterInWorldSet::ApplyRegisterTypes).
+/// If you're not using [`RegisterInWorldPlugin`](bevy_register_in_world::app::RegisterInWorldPlugin),
+/// add this system to the [`AddingSystems`](crate::add_systems::AddingSystems) schedule,
+/// before [`add_requested_systems`](crate::add_systems::add_requested_systems).
+pub fn apply_register_types(world: &mut World) {
+    let Some(mut events) = world.get_resource_mut::<ConsumableEvents<RegisterTypes>>() else {
+        return;
+    };
+    let requested: Vec<_> = events.read().map(|event| event.consume()).collect();
+
+    for RegisterTypes(types) in requested {
+        for registration in types {
+            debug!("Registering {} requested by an event", registration.name);
+            (registration.register)(world.into());
+            world.flush_commands();
+        }
+    }
+}
+
+/// Convenience trait to send [`RegisterTypes`] events.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.world_mut().request_register::<Foo>();
+/// harness.world_mut().request_register::<Foo>();
+/// assert!(!harness.world().is_registered::<Foo>());
+///
+/// harness.flush_registrations();
+/// assert!(harness.world().is_registered::<Foo>());
+/// ```
+pub trait WorldRegisterTypes {
+    /// Sends the [`RegisterTypes`] event.
+    fn register_types(&mut self, types: RegisterTypes);
+
+    /// Sends the [`RegisterTypes`] event registering `T`.
+    fn request_register<T: RegisterInWorld>(&mut self) {
+        self.register_types(RegisterTypes::of::<T>());
+    }
+}
+
+impl WorldRegisterTypes for DeferredWorld<'_> {
+    /// If [`ConsumableEvents<RegisterTypes>`] doesn't exist yet, the event is sent through commands,
+    /// which initialize the resource first.
+    fn register_types(&mut self, types: RegisterTypes) {
+        match self.get_resource_mut::<ConsumableEvents<RegisterTypes>>() {
+            Some(mut events) => events.send(types),
+            None => self.commands().add(|world: &mut World| {
+                world
+                    .get_resource_or_insert_with::<ConsumableEvents<RegisterTypes>>(Default::default)
+                    .send(types);
+            }),
+        }
+    }
+}
+
+impl WorldRegisterTypes for World {
+    /// Initializes [`ConsumableEvents<RegisterTypes>`] if it doesn't exist yet.
+    fn register_types(&mut self, types: RegisterTypes) {
+        self.get_resource_or_insert_with::<ConsumableEvents<RegisterTypes>>(Default::default)
+            .send(types);
+    }
+}
@@ -0,0 +1,291 @@
+//! Value-level registration, deduplicated by name instead of by type
+
+use std::{borrow::Cow, sync::Arc};
+
+use bevy_ecs::{
+    system::{Commands, Resource},
+    world::{DeferredWorld, World},
+};
+use bevy_utils::{
+    tracing::{debug, warn},
+    HashMap,
+};
+
+use crate::{RegisterExtension, RegisterInWorld, RegisteredTypes};
+
+/// Named group of setup closures, that is registered once per name.
+/// Complements [`RegisterInWorld`] for registrations
+/// that are easier to express as values than as types.
+///
+/// Closures run with exclusive access to the world, in the order they were added in.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{
+///     registration_bundle::{RegisterValue, RegistrationBundle},
+///     RegisteredTypes,
+/// };
+///
+/// #[derive(Resource, Default)]
+/// struct Log(Vec<&'static str>);
+///
+/// fn terrain_chunks() -> RegistrationBundle {
+///     RegistrationBundle::new("terrain_chunks")
+///         .with(|world: &mut World| world.resource_mut::<Log>().0.push("first"))
+///         .with(|world: &mut World| world.resource_mut::<Log>().0.push("second"))
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Log>();
+///
+/// world.register_value(terrain_chunks());
+/// world.register_value(terrain_chunks());
+/// assert_eq!(world.resource::<Log>().0, ["first", "second"]);
+///
+/// world.commands().register_value(terrain_chunks());
+/// world.flush();
+/// assert_eq!(world.resource::<Log>().0, ["first", "second"]);
+/// assert!(world.resource::<RegisteredTypes>().is_registered_name("terrain_chunks"));
+/// ```
+pub struct RegistrationBundle {
+    name: Cow<'static, str>,
+    setups: Vec<Setup>,
+}
+
+/// Setup closure of a [`RegistrationBundle`].
+type Setup = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+impl RegistrationBundle {
+    /// Creates an empty bundle. Bundles with the same `name` are registered only once.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            setups: Vec::new(),
+        }
+    }
+
+    /// Adds the setup closure, that runs after all the previously added ones.
+    pub fn with(mut self, setup: impl FnOnce(&mut World) + Send + Sync + 'static) -> Self {
+        self.setups.push(Box::new(setup));
+        self
+    }
+
+    /// Name of the bundle.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Amount of setup closures.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.setups.len()
+    }
+
+    /// Returns `true` if the bundle has no setup closures.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.setups.is_empty()
+    }
+}
+
+/// Registrations selected by name at runtime, for example from a config string.
+/// Complements type-keyed registration for data-driven scenarios.
+///
+/// A registration runs once per name, names are recorded in [`RegisteredTypes`]
+/// the same as the names of [`RegistrationBundle`]s.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{
+///     prelude::*,
+///     registration_bundle::{RegisterValue, RegistrationTable},
+/// };
+///
+/// #[derive(Resource, Default)]
+/// struct Backend(Vec<&'static str>);
+///
+/// struct Vulkan;
+///
+/// impl RegisterInWorld for Vulkan {
+///     fn register(mut world: DeferredWorld) {
+///         world.resource_mut::<Backend>().0.push("vulkan");
+///     }
+/// }
+///
+/// let mut table = RegistrationTable::default();
+/// table.insert("software", |world: &mut World| world.resource_mut::<Backend>().0.push("software"));
+/// table.insert_type::<Vulkan>("vulkan");
+///
+/// let mut world = World::new();
+/// world.init_resource::<Backend>();
+/// world.insert_resource(table);
+///
+/// let config = "software";
+/// world.register_named(config);
+/// world.register_named(config);
+/// world.register_named("vulkan");
+/// world.register_named("metal");
+/// assert_eq!(world.resource::<Backend>().0, ["software", "vulkan"]);
+/// assert!(world.is_registered::<Vulkan>());
+/// ```
+///
+/// The table stays in the world while a registration runs, so registrations can register other names.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_register_in_world::{
+///     registration_bundle::{RegisterValue, RegistrationTable},
+///     RegisteredTypes,
+/// };
+///
+/// let mut table = RegistrationTable::default();
+/// table.insert("renderer", |world: &mut World| world.register_named("vulkan"));
+/// table.insert("vulkan", |_world: &mut World| {});
+///
+/// let mut world = World::new();
+/// world.insert_resource(table);
+/// world.register_named("renderer");
+///
+/// let registered = world.resource::<RegisteredTypes>();
+/// assert!(registered.is_registered_name("renderer"));
+/// assert!(registered.is_registered_name("vulkan"));
+/// ```
+#[derive(Resource, Default)]
+pub struct RegistrationTable {
+    registrations: HashMap<String, NamedRegistration>,
+}
+
+/// Registration in a [`RegistrationTable`].
+type NamedRegistration = Arc<dyn Fn(&mut World) + Send + Sync>;
+
+impl RegistrationTable {
+    /// Adds the registration under the `name`, replacing the registration that was there.
+    pub fn insert(&mut self, name: impl Into<String>, register: impl Fn(&mut World) + Send + Sync + 'static) {
+        self.registrations.insert(name.into(), Arc::new(register));
+    }
+
+    /// Adds the registration of the type `T` under the `name`.
+    pub fn insert_type<T: RegisterInWorld>(&mut self, name: impl Into<String>) {
+        self.insert(name, |world| world.register::<T>());
+    }
+
+    /// Returns `true` if there is a registration under the `name`.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.registrations.contains_key(name)
+    }
+
+    /// Amount of registrations in the table.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.registrations.len()
+    }
+
+    /// Returns `true` if the table is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+
+    /// Runs the registration under the `name`, unless the `name` was already registered in [`RegisteredTypes`].
+    /// Returns `true` if the registration ran. Logs a warning if there is no registration under the `name`.
+    /// Initializes [`RegisteredTypes`] if it doesn't exist yet.
+    pub fn register_named(&self, name: &str, world: &mut World) -> bool {
+        match self.registration(name) {
+            Some(register) => run_named_registration(name, &register, world),
+            None => false,
+        }
+    }
+
+    /// The registration under the `name`. Logs a warning if there is none.
+    fn registration(&self, name: &str) -> Option<NamedRegistration> {
+        let registration = self.registrations.get(name).cloned();
+        if registration.is_none() {
+            warn!("There is no registration named `{name}` in the `RegistrationTable`");
+        }
+        registration
+    }
+}
+
+/// Runs the `register`, unless the `name` was already registered in [`RegisteredTypes`].
+/// Returns `true` if the registration ran.
+fn run_named_registration(name: &str, register: &NamedRegistration, world: &mut World) -> bool {
+    let registered = world.get_resource_or_insert_with::<RegisteredTypes>(Default::default);
+    if registered.is_registered_name(name) {
+        return false;
+    }
+    registered.into_inner().register_name(name.to_owned());
+
+    debug!("Registering `{name}`");
+    register(world);
+    world.flush_commands();
+    true
+}
+
+/// Extension trait to register [`RegistrationBundle`]s and names from [`RegistrationTable`].
+pub trait RegisterValue {
+    /// Runs the setup closures of the `bundle`, unless a bundle with the same name
+    /// was already registered in [`RegisteredTypes`].
+    fn register_value(&mut self, bundle: RegistrationBundle);
+
+    /// Runs the registration under the `name` in the [`RegistrationTable`] resource,
+    /// see [`RegistrationTable::register_named`]. Logs a warning if the resource doesn't exist.
+    fn register_named(&mut self, name: &str);
+}
+
+impl RegisterValue for World {
+    /// Initializes [`RegisteredTypes`] if it doesn't exist yet.
+    fn register_value(&mut self, bundle: RegistrationBundle) {
+        let RegistrationBundle { name, setups } = bundle;
+
+        let registered = self.get_resource_or_insert_with::<RegisteredTypes>(Default::default);
+        if registered.is_registered_name(&name) {
+            return;
+        }
+        registered.into_inner().register_name(name.clone());
+
+        debug!("Registering `{name}`");
+        for setup in setups {
+            setup(self);
+        }
+        self.flush_commands();
+    }
+
+    /// The registration is cloned out of the table before it runs, so it can register other names.
+    fn register_named(&mut self, name: &str) {
+        let Some(table) = self.get_resource::<RegistrationTable>() else {
+            warn!("`RegistrationTable` doesn't exist, can't register `{name}`");
+            return;
+        };
+        if let Some(register) = table.registration(name) {
+            run_named_registration(name, &register, self);
+        }
+    }
+}
+
+impl RegisterValue for Commands<'_, '_> {
+    /// Queues the registration, so closures run when the commands are applied.
+    fn register_value(&mut self, bundle: RegistrationBundle) {
+        self.add(move |world: &mut World| world.register_value(bundle));
+    }
+
+    /// Queues the registration, so it runs when the commands are applied.
+    fn register_named(&mut self, name: &str) {
+        let name = name.to_owned();
+        self.add(move |world: &mut World| world.register_named(&name));
+    }
+}
+
+impl RegisterValue for DeferredWorld<'_> {
+    /// Queues the registration through commands, see the implementation for [`Commands`].
+    fn register_value(&mut self, bundle: RegistrationBundle) {
+        self.commands().register_value(bundle);
+    }
+
+    /// Queues the registration through commands, see the implementation for [`Commands`].
+    fn register_named(&mut self, name: &str) {
+        self.commands().register_named(name);
+    }
+}
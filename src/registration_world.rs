@@ -0,0 +1,120 @@
+//! Helpers for bodies of [`RegisterInWorld::register`](crate::RegisterInWorld::register)
+
+use bevy_ecs::{
+    event::{Event, EventRegistry, Events},
+    system::Resource,
+    world::{DeferredWorld, World},
+};
+
+use crate::add_systems::WorldAddSystems;
+
+/// Extension trait for [`DeferredWorld`] that makes [`register`](crate::RegisterInWorld::register)
+/// bodies read like app-builder code.
+///
+/// All the methods queue commands, so their effects are only visible after the commands
+/// are flushed. [`World::register`](crate::RegisterExtension::register) flushes them right after
+/// the call to `register`, and when registration happens in a hook, they are flushed
+/// together with the rest of the commands queued by the hook.
+///
+/// [`add_systems`](WorldAddSystems::add_systems) is available through the [`WorldAddSystems`] supertrait.
+pub trait RegistrationWorld: WorldAddSystems {
+    /// Queues initialization of the resource using its [`Default`] implementation.
+    /// Does nothing if the resource already exists.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Counter(u32);
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.init_resource_deferred::<Counter>();
+    ///         // Commands are not flushed yet
+    ///         assert!(!world.contains_resource::<Counter>());
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.register::<Foo>();
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    /// ```
+    fn init_resource_deferred<R: Resource + Default>(&mut self);
+
+    /// Queues insertion of the resource, overwriting the existing one.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct Counter(u32);
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.insert_resource_deferred(Counter(5));
+    ///         assert_eq!(world.resource::<Counter>().0, 0);
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Counter(0));
+    /// world.register::<Foo>();
+    /// assert_eq!(world.resource::<Counter>().0, 5);
+    /// ```
+    fn insert_resource_deferred<R: Resource>(&mut self, resource: R);
+
+    /// Queues setting up the event storage for the event type, the same way `App::add_event` does.
+    /// Does nothing if the event storage already exists.
+    ///
+    /// Events are updated by bevy's `event_update_system`, which updates every event
+    /// added this way, so no per-event system is added.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::DeferredWorld;
+    /// use bevy_register_in_world::prelude::*;
+    ///
+    /// #[derive(Event)]
+    /// struct Ping;
+    ///
+    /// struct Foo;
+    ///
+    /// impl RegisterInWorld for Foo {
+    ///     fn register(mut world: DeferredWorld) {
+    ///         world.add_event_deferred::<Ping>();
+    ///         assert!(!world.contains_resource::<Events<Ping>>());
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.register::<Foo>();
+    /// world.send_event(Ping).unwrap();
+    /// ```
+    fn add_event_deferred<E: Event>(&mut self);
+}
+
+impl RegistrationWorld for DeferredWorld<'_> {
+    fn init_resource_deferred<R: Resource + Default>(&mut self) {
+        self.commands().init_resource::<R>();
+    }
+
+    fn insert_resource_deferred<R: Resource>(&mut self, resource: R) {
+        self.commands().insert_resource(resource);
+    }
+
+    fn add_event_deferred<E: Event>(&mut self) {
+        self.commands().add(|world: &mut World| {
+            if !world.contains_resource::<Events<E>>() {
+                EventRegistry::register_event::<E>(world);
+            }
+        });
+    }
+}
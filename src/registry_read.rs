@@ -0,0 +1,83 @@
+//! Reading registrations from systems without naming the resource
+
+use std::ops::Deref;
+
+use bevy_ecs::system::{Res, SystemParam};
+
+use crate::{RegisterInWorld, RegisteredTypes};
+
+/// Read-only [`SystemParam`] over [`RegisteredTypes`].
+/// Like [`Res<RegisteredTypes>`], panics if the resource doesn't exist,
+/// which doesn't happen when [`RegisterInWorldPlugin`](crate::app::RegisterInWorldPlugin) is added.
+///
+/// Dereferences to [`RegisteredTypes`] for everything that is not exposed directly.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{ReadOnlySystemParam, RunSystemOnce};
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, registry_read::RegistryRead};
+///
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// struct Bar;
+///
+/// impl RegisterInWorld for Bar {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// fn assert_read_only<P: ReadOnlySystemParam>() {}
+/// assert_read_only::<RegistryRead>();
+///
+/// let mut world = World::new();
+/// world.register::<Foo>();
+///
+/// let (foo, bar, count, names) = world.run_system_once(|registry: RegistryRead| {
+///     (
+///         registry.is_registered::<Foo>(),
+///         registry.is_registered::<Bar>(),
+///         registry.count(),
+///         registry.names().collect::<Vec<_>>(),
+///     )
+/// });
+/// assert!(foo);
+/// assert!(!bar);
+/// assert_eq!(count, 1);
+/// assert_eq!(names, [std::any::type_name::<Foo>()]);
+/// ```
+#[derive(SystemParam)]
+pub struct RegistryRead<'w> {
+    registered: Res<'w, RegisteredTypes>,
+}
+
+impl RegistryRead<'_> {
+    /// Returns wether the type is registered or not.
+    #[inline]
+    pub fn is_registered<T: RegisterInWorld>(&self) -> bool {
+        self.registered.is_registered::<T>()
+    }
+
+    /// Returns the amount of registered types.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.registered.len()
+    }
+
+    /// Returns names of the registered types, in no particular order.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.registered.type_names()
+    }
+}
+
+impl Deref for RegistryRead<'_> {
+    type Target = RegisteredTypes;
+
+    fn deref(&self) -> &Self::Target {
+        &self.registered
+    }
+}
@@ -0,0 +1,279 @@
+//! Utilities for testing code that uses registration, without a full `App`
+
+use bevy_ecs::{
+    bundle::Bundle,
+    entity::Entity,
+    schedule::{Schedule, ScheduleLabel, Schedules},
+    world::World,
+};
+use bevy_utils::tracing::{
+    field::{Field, Visit},
+    span,
+    subscriber::{with_default, Interest},
+    Event, Level, Metadata, Subscriber,
+};
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    add_systems::{adding_systems_configs, init_adding_systems_resources, AddingSystems},
+    init_plugin_registration_resources, RegisterExtension, RegisterInWorld,
+};
+#[cfg(feature = "bevy_app")]
+use {crate::app::RegisterInWorldPlugin, bevy_app::App};
+
+/// Creates an [`App`] with [`RegisterInWorldPlugin`] added and finished,
+/// so components can be spawned and the app updated right away.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, test_utils::{assert_registered, registration_test_app}};
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(Update, || {});
+///     }
+/// }
+///
+/// let mut app = registration_test_app();
+/// app.world_mut().spawn(Foo);
+/// app.update();
+///
+/// assert_registered::<Foo>(&app);
+/// assert_eq!(app.world().resource::<Schedules>().get(Update).unwrap().systems_len(), 1);
+/// ```
+#[cfg(feature = "bevy_app")]
+pub fn registration_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(RegisterInWorldPlugin::default());
+    app.finish();
+    app.cleanup();
+    app
+}
+
+/// Function form of [`assert_registered!`](crate::assert_registered),
+/// for anything that implements [`RegisterExtension`].
+///
+/// # Panics
+/// If the type is not registered.
+#[track_caller]
+pub fn assert_registered<T: RegisterInWorld>(world: &impl RegisterExtension) {
+    crate::assert_registered!(world, T);
+}
+
+/// Runs `f` with a subscriber that captures the messages of the events at the `level`,
+/// and returns them in the order they were logged.
+///
+/// ```
+/// # use bevy_utils::tracing::{debug, warn, Level};
+/// use bevy_register_in_world::test_utils::capture_logs;
+///
+/// let logs = capture_logs(Level::WARN, || {
+///     debug!("Not captured");
+///     warn!("Captured {}", 1);
+/// });
+/// assert_eq!(logs, ["Captured 1"]);
+/// ```
+pub fn capture_logs(level: Level, f: impl FnOnce()) -> Vec<String> {
+    let capture = Capture {
+        level,
+        messages: Default::default(),
+    };
+    with_default(capture.clone(), f);
+    let mut messages = capture.messages.lock().unwrap();
+    std::mem::take(&mut *messages)
+}
+
+/// Subscriber of [`capture_logs`].
+#[derive(Clone)]
+struct Capture {
+    level: Level,
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+/// Records the message of an event.
+struct MessageVisitor<'a>(&'a mut Vec<String>);
+
+impl Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.push(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for Capture {
+    // Other captures may run in the same process with another level, so the interest isn't cached
+    fn register_callsite(&self, _metadata: &'static Metadata<'static>) -> Interest {
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        *metadata.level() == self.level
+    }
+
+    fn new_span(&self, _span: &span::Attributes) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event) {
+        let mut messages = self.messages.lock().unwrap();
+        event.record(&mut MessageVisitor(&mut messages));
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+/// Wraps a [`World`] that is set up for registration and adding systems,
+/// like [`RegisterInWorldPlugin`] sets up the main app,
+/// but [`AddingSystems`] only runs when [`flush_registrations`](RegistrationTestHarness::flush_registrations) is called.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_ecs::world::DeferredWorld;
+/// use bevy_register_in_world::{prelude::*, test_utils::RegistrationTestHarness};
+///
+/// #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone)]
+/// struct MySchedule;
+///
+/// #[derive(ComponentAutoRegister)]
+/// struct Foo;
+///
+/// impl RegisterInWorld for Foo {
+///     fn register(mut world: DeferredWorld) {
+///         world.add_systems(MySchedule, foo_system);
+///     }
+/// }
+///
+/// fn foo_system() {}
+///
+/// let mut harness = RegistrationTestHarness::new();
+/// harness.spawn_with(Foo);
+/// harness.assert_registered::<Foo>();
+///
+/// harness.flush_registrations();
+/// harness.assert_system_in_schedule(MySchedule, "foo_system");
+///
+/// harness.run_schedule(MySchedule);
+/// harness.assert_system_in_schedule(MySchedule, "foo_system");
+/// ```
+pub struct RegistrationTestHarness {
+    world: World,
+}
+
+impl Default for RegistrationTestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegistrationTestHarness {
+    /// Creates an empty world with the resources [`RegisterInWorldPlugin`]
+    /// initializes and [`AddingSystems`] schedule.
+    pub fn new() -> Self {
+        let mut world = World::new();
+        init_plugin_registration_resources(&mut world);
+        init_adding_systems_resources(&mut world);
+
+        let mut adding_systems = Schedule::new(AddingSystems);
+        adding_systems.add_systems(adding_systems_configs());
+        world.add_schedule(adding_systems);
+
+        Self { world }
+    }
+
+    /// The wrapped world.
+    #[inline]
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// The wrapped world.
+    #[inline]
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Spawns an entity with the `bundle`, registering its auto registered components.
+    pub fn spawn_with(&mut self, bundle: impl Bundle) -> Entity {
+        self.world.spawn(bundle).id()
+    }
+
+    /// Registers the type, the same as [`RegisterExtension::register`].
+    pub fn register<T: RegisterInWorld>(&mut self) -> &mut Self {
+        self.world.register::<T>();
+        self
+    }
+
+    /// Applies queued commands and runs [`AddingSystems`], so all the requested systems
+    /// are added to [`Schedules`] and all the requested observers are spawned.
+    pub fn flush_registrations(&mut self) -> &mut Self {
+        self.world.flush();
+        self.world.run_schedule(AddingSystems);
+        self
+    }
+
+    /// Runs the schedule.
+    ///
+    /// # Panics
+    /// If the schedule doesn't exist.
+    pub fn run_schedule(&mut self, label: impl ScheduleLabel) -> &mut Self {
+        self.world.run_schedule(label);
+        self
+    }
+
+    /// # Panics
+    /// If the type is not registered.
+    #[track_caller]
+    pub fn assert_registered<T: RegisterInWorld>(&self) {
+        assert_registered::<T>(&self.world);
+    }
+
+    /// # Panics
+    /// If the schedule doesn't contain a system with the `name`. A system matches if its
+    /// name is equal to the `name`, or its path ends with `::name`.
+    #[track_caller]
+    pub fn assert_system_in_schedule(&self, label: impl ScheduleLabel, name: &str) {
+        let label = label.intern();
+        let names = self.system_names(label);
+        assert!(
+            names
+                .iter()
+                .any(|system| system == name || system.ends_with(&format!("::{name}"))),
+            "System `{name}` is not in {label:?}. Systems in the schedule: {names:?}",
+        );
+    }
+
+    fn system_names(&self, label: impl ScheduleLabel) -> Vec<String> {
+        let Some(schedule) = self
+            .world
+            .get_resource::<Schedules>()
+            .and_then(|schedules| schedules.get(label))
+        else {
+            return Vec::new();
+        };
+
+        // Systems are moved out of the graph when the schedule is initialized
+        let uninitialized = schedule
+            .graph()
+            .systems()
+            .map(|(_, system, _)| system.name().into_owned());
+        let initialized = schedule
+            .systems()
+            .into_iter()
+            .flatten()
+            .map(|(_, system)| system.name().into_owned());
+        uninitialized.chain(initialized).collect()
+    }
+}
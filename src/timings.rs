@@ -0,0 +1,110 @@
+//! Measuring how long registrations take
+
+use bevy_ecs::{component::Tick, system::Resource};
+use bevy_utils::Duration;
+
+/// Duration of a call to [`RegisterInWorld::register`](crate::RegisterInWorld::register).
+#[derive(Debug, Clone, Copy)]
+pub struct RegistrationTiming {
+    /// Name of the registered type.
+    pub type_name: &'static str,
+    /// How long the registration took.
+    pub duration: Duration,
+    /// Change tick of the world when the registration happened.
+    pub tick: Tick,
+}
+
+/// When this resource exists, every registration is measured and recorded here,
+/// both during hooks and during [`World::register`](crate::RegisterExtension::register).
+/// If a registration takes longer than the [`threshold`](RegistrationTimings::threshold),
+/// a warning naming the type is logged.
+///
+/// Inserted by [`RegisterInWorldPlugin::warn_if_register_exceeds`](crate::app::RegisterInWorldPlugin::warn_if_register_exceeds).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::DeferredWorld;
+/// # use std::time::Duration;
+/// use bevy_register_in_world::{prelude::*, timings::RegistrationTimings};
+///
+/// struct Slow;
+///
+/// impl RegisterInWorld for Slow {
+///     fn register(_world: DeferredWorld) {
+///         std::thread::sleep(Duration::from_millis(5));
+///     }
+/// }
+///
+/// struct Fast;
+///
+/// impl RegisterInWorld for Fast {
+///     fn register(_world: DeferredWorld) {}
+/// }
+///
+/// let mut world = World::new();
+/// world.insert_resource(RegistrationTimings::new(Duration::from_millis(2)));
+/// world.register::<Slow>();
+/// world.register::<Fast>();
+///
+/// let timings = world.resource::<RegistrationTimings>();
+/// assert_eq!(timings.records().len(), 2);
+/// assert!(timings.records()[0].duration >= Duration::from_millis(5));
+///
+/// let exceeded: Vec<_> = timings.exceeded().map(|timing| timing.type_name).collect();
+/// assert_eq!(exceeded, [std::any::type_name::<Slow>()]);
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RegistrationTimings {
+    threshold: Option<Duration>,
+    records: Vec<RegistrationTiming>,
+}
+
+impl RegistrationTimings {
+    /// Creates the resource that warns about registrations that take longer than `threshold`.
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold: Some(threshold),
+            records: Vec::new(),
+        }
+    }
+
+    /// Registrations that take longer than this are warned about.
+    /// If `None`, registrations are only recorded.
+    #[inline]
+    pub fn threshold(&self) -> Option<Duration> {
+        self.threshold
+    }
+
+    /// Sets the [`threshold`](RegistrationTimings::threshold).
+    #[inline]
+    pub fn set_threshold(&mut self, threshold: Option<Duration>) {
+        self.threshold = threshold;
+    }
+
+    /// All the recorded registrations, in the order they happened in.
+    #[inline]
+    pub fn records(&self) -> &[RegistrationTiming] {
+        &self.records
+    }
+
+    /// Recorded registrations that took longer than the [`threshold`](RegistrationTimings::threshold).
+    pub fn exceeded(&self) -> impl Iterator<Item = &RegistrationTiming> {
+        self.records
+            .iter()
+            .filter(|timing| self.threshold.is_some_and(|threshold| timing.duration > threshold))
+    }
+
+    /// Removes all the records.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    /// Returns `true` if the registration exceeded the threshold.
+    pub(crate) fn record(&mut self, timing: RegistrationTiming) -> bool {
+        let exceeded = self
+            .threshold
+            .is_some_and(|threshold| timing.duration > threshold);
+        self.records.push(timing);
+        exceeded
+    }
+}